@@ -1,15 +1,18 @@
-use crate::output::{ko, ok};
-use crate::support::{Language, Support};
+use crate::hook::Hook;
+use crate::output::{clear, ko, ok, show_cursor};
+use crate::report::elapsed;
+use crate::support::{detect, discover, Detection, Language, Project, Support, ROOT_PROJECT};
+use crate::task::{seconds, Task, TaskCategory, TaskResult, TaskStatus};
+use crate::tasks;
 use cli_table::{format::Align, format::Justify, Color, Table};
-use crossterm::cursor::Show;
-use crossterm::execute;
-use crossterm::terminal::{Clear, ClearType};
 use inquire::{Confirm, MultiSelect};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{stdout, Write};
-use std::path::Path;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
 use std::process::ExitCode;
+use std::time::Duration;
 
 #[doc = "All checkup title messages"]
 pub const ZUU_TITLES: [&str; 9] = [
@@ -63,11 +66,484 @@ pub const ZUU_KO: [&str; 9] = [
     "The code contains errors.",
 ];
 
+#[doc = "The language name detecting the project languages at run time"]
+pub const AUTO: &str = "auto";
+
 #[derive(Serialize, Deserialize)]
 #[doc = "The tux configuration"]
 pub struct Config {
+    #[serde(default)]
+    #[doc = "The language names, case insensitive, `auto` adds the detected languages"]
     pub languages: Vec<String>,
     pub strict: bool,
+    #[serde(default)]
+    #[doc = "Number of tasks to run at the same time, all the cpus by default"]
+    pub jobs: Option<usize>,
+    #[serde(default)]
+    #[doc = "Maximum duration in seconds of the tasks without their own timeout"]
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    #[doc = "Tasks overrides by language then by task name, `[tasks.Rust.test]`"]
+    pub tasks: BTreeMap<String, BTreeMap<String, TaskConfig>>,
+    #[serde(default)]
+    #[doc = "The projects of a monorepo, `[[projects]]`"]
+    pub projects: Vec<ProjectConfig>,
+    #[serde(default)]
+    #[doc = "Add a project for every directory holding a manifest"]
+    pub discover: bool,
+    #[serde(default)]
+    #[doc = "The task selections chosen with `--profile`, `[profiles.pre-commit]`"]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[doc = "A selection of tasks, every task when empty"]
+pub struct Profile {
+    #[serde(default)]
+    #[doc = "The task names to execute"]
+    pub tasks: Vec<String>,
+    #[serde(default)]
+    #[doc = "The task categories to execute"]
+    pub categories: Vec<TaskCategory>,
+}
+
+impl Profile {
+    #[must_use]
+    #[doc = "Check if the profile executes the task"]
+    pub fn keeps(&self, task: &Task) -> bool {
+        (self.tasks.is_empty() && self.categories.is_empty())
+            || self.tasks.contains(&task.name)
+            || self.categories.contains(&task.category)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[doc = "A project declared in tux.toml"]
+pub struct ProjectConfig {
+    #[serde(default)]
+    #[doc = "The key of the reports, the path by default"]
+    pub name: Option<String>,
+    #[doc = "The project directory, relative to tux.toml"]
+    pub path: PathBuf,
+    #[serde(default)]
+    #[doc = "The language names, detected when empty"]
+    pub languages: Vec<String>,
+}
+
+impl ProjectConfig {
+    #[must_use]
+    #[doc = "The key of the reports"]
+    pub fn name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| self.path.to_string_lossy().to_string())
+    }
+}
+
+#[doc = "The languages named in the support order, `auto` is replaced by the languages surely detected in the root"]
+fn resolve(names: &[String], root: &Path) -> Vec<Language> {
+    let mut found: Vec<Language> = Vec::new();
+    for name in names {
+        if name.eq_ignore_ascii_case(AUTO) {
+            found.extend(
+                detect(root)
+                    .iter()
+                    .filter(|detection| detection.is_certain())
+                    .map(|detection| detection.language),
+            );
+        } else if let Ok(language) = name.parse::<Language>() {
+            found.push(language);
+        }
+    }
+    Support::new()
+        .supported()
+        .into_iter()
+        .filter(|language| found.contains(language))
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+#[doc = "A command written as a command line or as a program followed by its arguments"]
+pub enum CommandLine {
+    Line(String),
+    Argv(Vec<String>),
+}
+
+impl CommandLine {
+    #[doc = "Replace the task command"]
+    pub fn apply(&self, task: &mut Task, shell: bool) {
+        match self {
+            Self::Line(line) if shell => task.set_shell_command(line),
+            Self::Line(line) => task.set_command(line),
+            Self::Argv(argv) if shell => task.set_shell_command(&shell_words::join(argv)),
+            Self::Argv(argv) => {
+                task.program = argv.first().cloned().unwrap_or_default();
+                task.args = argv.iter().skip(1).cloned().collect();
+                task.shell = false;
+            }
+        }
+    }
+
+    #[must_use]
+    #[doc = "Check if no program is given"]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Line(line) => line.trim().is_empty(),
+            Self::Argv(argv) => argv.first().is_none_or(String::is_empty),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[doc = "User definition of a task, replace the built-in task with the same name or add a new one"]
+pub struct TaskConfig {
+    #[serde(default)]
+    #[doc = "Remove the task from the language table"]
+    pub disabled: bool,
+    pub title: Option<String>,
+    pub command: Option<CommandLine>,
+    #[doc = "Run the command through `sh -c`, only for pipes, redirections or `&&`"]
+    pub shell: Option<bool>,
+    pub success: Option<String>,
+    pub failure: Option<String>,
+    pub category: Option<TaskCategory>,
+    pub timeout: Option<u64>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub cwd: Option<PathBuf>,
+    #[doc = "Names of the tasks to finish before this one"]
+    pub after: Option<Vec<String>>,
+    #[doc = "Names of the tasks to pass before this one, skipped otherwise"]
+    pub needs: Option<Vec<String>>,
+    #[doc = "The command accepts file arguments, false by default for a new command"]
+    pub files: Option<bool>,
+    #[doc = "Number of executions after a failure"]
+    pub retries: Option<u32>,
+    #[doc = "Seconds to wait before the first retry, doubled at each retry"]
+    pub backoff: Option<u64>,
+    #[doc = "Glob patterns of the files read by the command, the result is cached when set"]
+    pub inputs: Option<Vec<String>>,
+}
+
+impl TaskConfig {
+    #[doc = "Replace the task fields defined by the user"]
+    #[must_use]
+    pub fn apply(&self, mut task: Task) -> Task {
+        if let Some(title) = &self.title {
+            task.title.clone_from(title);
+        }
+        if let Some(command) = &self.command {
+            command.apply(&mut task, self.shell.unwrap_or(false));
+            task.files = self.files.unwrap_or(false);
+        } else if let Some(shell) = self.shell {
+            if shell && !task.shell {
+                task.set_shell_command(&task.command_line());
+            }
+        }
+        if let Some(success) = &self.success {
+            task.success.clone_from(success);
+        }
+        if let Some(failure) = &self.failure {
+            task.failure.clone_from(failure);
+        }
+        if let Some(category) = self.category {
+            task.category = category;
+        }
+        if let Some(timeout) = self.timeout {
+            task.timeout = Some(timeout);
+        }
+        if let Some(env) = &self.env {
+            task.env.extend(env.clone());
+        }
+        if let Some(cwd) = &self.cwd {
+            task.cwd = Some(cwd.clone());
+        }
+        if let Some(after) = &self.after {
+            task.after.clone_from(after);
+        }
+        if let Some(needs) = &self.needs {
+            task.needs.clone_from(needs);
+        }
+        if let Some(files) = self.files {
+            task.files = files;
+        }
+        if let Some(retries) = self.retries {
+            task.retries = retries;
+        }
+        if let Some(backoff) = self.backoff {
+            task.backoff = backoff;
+        }
+        if let Some(inputs) = &self.inputs {
+            task.inputs.clone_from(inputs);
+        }
+        task
+    }
+
+    #[doc = "Build a new task, the command is required"]
+    #[must_use]
+    pub fn create(&self, name: &str) -> Option<Task> {
+        if self.command.as_ref()?.is_empty() {
+            return None;
+        }
+        let title: String = self
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Running the {name} task"));
+        Some(self.apply(Task::new(
+            name,
+            TaskCategory::Custom,
+            title.as_str(),
+            "",
+            format!("The {name} task passed").as_str(),
+            format!("The {name} task failed").as_str(),
+        )))
+    }
+}
+
+///
+/// # Cycle
+///
+/// Find a cycle in the `after` and `needs` graph of the tasks
+///
+#[must_use]
+pub fn cycle(todo: &[Task]) -> Option<Vec<String>> {
+    fn visit<'a>(
+        task: &'a Task,
+        todo: &'a [Task],
+        path: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|name| name.eq(&task.name)) {
+            let mut cycle: Vec<String> = path[start..].iter().map(ToString::to_string).collect();
+            cycle.push(task.name.to_string());
+            return Some(cycle);
+        }
+        if done.contains(&task.name.as_str()) {
+            return None;
+        }
+        path.push(task.name.as_str());
+        for name in task.prerequisites() {
+            if let Some(next) = todo.iter().find(|other| other.name.eq(name)) {
+                if let Some(cycle) = visit(next, todo, path, done) {
+                    return Some(cycle);
+                }
+            }
+        }
+        path.pop();
+        done.push(task.name.as_str());
+        None
+    }
+    let mut done: Vec<&str> = Vec::new();
+    todo.iter()
+        .find_map(|task| visit(task, todo, &mut Vec::new(), &mut done))
+}
+
+impl Config {
+    ///
+    /// # Languages
+    ///
+    /// The configured languages of the repository root in the support order,
+    /// `auto` is replaced by the languages detected from the current directory
+    ///
+    #[must_use]
+    pub fn languages(&self) -> Vec<Language> {
+        resolve(&self.languages, Path::new(ROOT_PROJECT))
+    }
+
+    ///
+    /// # Projects
+    ///
+    /// The repository root when it has languages, the declared projects,
+    /// then the discovered directories not declared
+    ///
+    #[must_use]
+    pub fn projects(&self) -> Vec<Project> {
+        let mut projects: Vec<Project> = Vec::new();
+        let languages: Vec<Language> = self.languages();
+        if !languages.is_empty() {
+            projects.push(Project {
+                name: ROOT_PROJECT.to_string(),
+                root: PathBuf::from(ROOT_PROJECT),
+                languages,
+            });
+        }
+        for project in &self.projects {
+            let names: Vec<String> = if project.languages.is_empty() {
+                vec![AUTO.to_string()]
+            } else {
+                project.languages.clone()
+            };
+            projects.push(Project {
+                name: project.name(),
+                root: project.path.clone(),
+                languages: resolve(&names, &project.path),
+            });
+        }
+        if self.discover {
+            for found in discover(Path::new(ROOT_PROJECT)) {
+                if !projects
+                    .iter()
+                    .any(|project| project.name.eq(&found.name) || project.root.eq(&found.root))
+                {
+                    projects.push(found);
+                }
+            }
+        }
+        projects
+    }
+
+    ///
+    /// # Profile
+    ///
+    /// The profile with the given name, none for a hook without profile to execute every task
+    ///
+    /// # Errors
+    ///
+    /// On an unknown profile
+    ///
+    pub fn profile(&self, name: &str) -> Result<Option<&Profile>, String> {
+        match self.profiles.get(name) {
+            Some(profile) => Ok(Some(profile)),
+            None if Hook::from_name(name).is_some() => Ok(None),
+            None => Err(format!("The profile {name} is not defined in tux.toml")),
+        }
+    }
+
+    #[doc = "The user overrides for a language"]
+    fn overrides(&self, language: &Language) -> Option<&BTreeMap<String, TaskConfig>> {
+        self.tasks
+            .iter()
+            .find(|(key, _)| key.parse::<Language>().is_ok_and(|key| key.eq(language)))
+            .map(|(_, overrides)| overrides)
+    }
+
+    ///
+    /// # Tasks
+    ///
+    /// The built-in tasks of the language with the user overrides applied.
+    /// The global timeout applies to the tasks without their own timeout.
+    /// Unknown names in the overrides are appended as new tasks,
+    /// the disabled tasks are removed from the `after` and `needs` of the others.
+    ///
+    #[must_use]
+    pub fn tasks(&self, language: &Language) -> Vec<Task> {
+        let mut todo: Vec<Task> = tasks(language);
+        for task in &mut todo {
+            task.timeout = task.timeout.or(self.timeout);
+        }
+        let Some(overrides) = self.overrides(language) else {
+            return todo;
+        };
+        todo = todo
+            .into_iter()
+            .filter_map(|task| match overrides.get(&task.name) {
+                Some(custom) if custom.disabled => None,
+                Some(custom) => Some(custom.apply(task)),
+                None => Some(task),
+            })
+            .collect();
+        for (name, custom) in overrides {
+            if custom.disabled || todo.iter().any(|task| task.name.eq(name)) {
+                continue;
+            }
+            if let Some(mut task) = custom.create(name) {
+                task.timeout = task.timeout.or(self.timeout);
+                todo.push(task);
+            }
+        }
+        for task in &mut todo {
+            for names in [&mut task.after, &mut task.needs] {
+                names.retain(|name| !overrides.get(name).is_some_and(|custom| custom.disabled));
+            }
+        }
+        todo
+    }
+
+    ///
+    /// # Validate
+    ///
+    /// Check the user tasks definitions
+    ///
+    /// # Errors
+    ///
+    /// On an unknown language, on a project outside the repository or declared twice, on a new task without command, on an empty command,
+    /// on an unknown task in `after` or `needs`, or on a dependency cycle
+    ///
+    pub fn validate(&self) -> Result<(), String> {
+        for name in self
+            .languages
+            .iter()
+            .chain(self.projects.iter().flat_map(|project| &project.languages))
+        {
+            if !name.eq_ignore_ascii_case(AUTO) {
+                name.parse::<Language>()?;
+            }
+        }
+        for key in self.tasks.keys() {
+            key.parse::<Language>()
+                .map_err(|error| format!("Invalid [tasks.{key}] => {error}"))?;
+        }
+        for (index, project) in self.projects.iter().enumerate() {
+            let name: String = project.name();
+            if !project.path.is_dir() {
+                return Err(format!("The project {name} path is not a directory"));
+            }
+            if project.path.is_absolute()
+                || project
+                    .path
+                    .components()
+                    .any(|component| component.eq(&Component::ParentDir))
+            {
+                return Err(format!(
+                    "The project {name} path must be inside the repository"
+                ));
+            }
+            if name.eq(ROOT_PROJECT)
+                || self.projects[..index]
+                    .iter()
+                    .any(|other| other.name().eq(&name))
+            {
+                return Err(format!("The project name {name} is already used"));
+            }
+        }
+        for language in Support::new().supported() {
+            let Some(overrides) = self.overrides(&language) else {
+                continue;
+            };
+            let todo: Vec<Task> = self.tasks(&language);
+            for task in &todo {
+                for name in task.prerequisites() {
+                    if !todo.iter().any(|other| other.name.eq(name)) {
+                        return Err(format!(
+                            "The {language} task {} waits for the unknown task {name}",
+                            task.name
+                        ));
+                    }
+                }
+            }
+            if let Some(cycle) = cycle(&todo) {
+                return Err(format!(
+                    "The {language} tasks {} depend on each other",
+                    cycle.join(" -> ")
+                ));
+            }
+            let builtin: Vec<Task> = tasks(&language);
+            for (name, custom) in overrides {
+                if custom.command.as_ref().is_some_and(CommandLine::is_empty) {
+                    return Err(format!("The {language} task {name} has an empty command"));
+                }
+                if custom.disabled || custom.command.is_some() {
+                    continue;
+                }
+                if !builtin.iter().any(|task| task.name.eq(name)) {
+                    return Err(format!(
+                        "The {language} task {name} is not a built-in task and has no command"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub const PROJECT_STRUCTURE_VALID: &str = "VALID";
@@ -97,11 +573,26 @@ pub const OUTDATED_NOT_VALID: &str = "OUTDATED";
 pub const DOCUMENTED_VALID: &str = "GENERATED";
 pub const DOCUMENTED_NOT_VALID: &str = "NOT GENERATED";
 
+pub const NOT_CHECKED: &str = "-";
+pub const SKIPPED: &str = "SKIPPED";
+pub const UNAFFECTED: &str = "UNAFFECTED";
+pub const TIMEOUT: &str = "TIMEOUT";
+pub const CANCELLED: &str = "CANCELLED";
+pub const FLAKY: &str = "FLAKY";
+pub const MISSING_TOOL: &str = "MISSING TOOL";
+
 pub const SUCCESS: i32 = 0;
 pub const FAILURE: i32 = 1;
 
-#[derive(Table)]
+#[derive(Table, Serialize)]
 pub struct Report {
+    #[table(
+        title = "ROOT",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub project: String, // . | backend
     #[table(
         title = "LANGUAGE",
         align = "Align::Top",
@@ -139,8 +630,6 @@ pub struct Report {
         color = "Color::White"
     )]
     pub test: String, // PASS | FAIL
-    #[table(title = "SECURE", justify = "Justify::Left", color = "Color::White")]
-    pub secure: String,
     #[table(
         title = "STANDARD",
         align = "Align::Top",
@@ -169,31 +658,185 @@ pub struct Report {
         color = "Color::White"
     )]
     pub lint: String, // Approved | Rejected
+    #[table(
+        title = "TIME",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White",
+        display_fn = "elapsed"
+    )]
+    #[serde(serialize_with = "seconds")]
+    pub duration: Duration, // sum of the task durations, serialized in seconds
     #[table(
         title = "EXIT",
         align = "Align::Top",
         justify = "Justify::Left",
         color = "Color::White"
     )]
-    pub code: i32, // 1 | 0
+    pub code: i32, // 1 | 0
+    #[table(
+        title = "CACHED",
+        align = "Align::Top",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub cached: usize, // tasks replayed from the cache
+    #[table(skip)]
+    pub tasks: Vec<TaskResult>,
 }
 
 impl Report {
     #[must_use]
     pub fn new() -> Self {
         Self {
+            project: ROOT_PROJECT.to_string(),
             language: Language::Unknown.to_string(),
-            project_structure: PROJECT_STRUCTURE_NOT_VALID.to_uppercase(),
-            licenses: PROJECT_LICENSE_NOT_VALID.to_uppercase(),
-            dependencies: BUILD_DEPENDENCIES_NOT_VALID.to_uppercase(),
-            audit: AUDIT_NOT_VALID.to_uppercase(),
-            test: TESTS_RESULTS_NOT_VALID.to_uppercase(),
-            secure: AUDIT_NOT_VALID.to_string(),
-            standard: RESPECT_OF_STANDARD_NOT_VALID.to_uppercase(),
-            documented: DOCUMENTED_NOT_VALID.to_uppercase(),
-            outdated: OUTDATED_NOT_VALID.to_uppercase(),
-            lint: CODE_NOT_VALID.to_uppercase(),
+            project_structure: NOT_CHECKED.to_string(),
+            licenses: NOT_CHECKED.to_string(),
+            dependencies: NOT_CHECKED.to_string(),
+            audit: NOT_CHECKED.to_string(),
+            test: NOT_CHECKED.to_string(),
+            standard: NOT_CHECKED.to_string(),
+            documented: NOT_CHECKED.to_string(),
+            outdated: NOT_CHECKED.to_string(),
+            lint: NOT_CHECKED.to_string(),
+            duration: Duration::ZERO,
             code: FAILURE,
+            cached: 0,
+            tasks: Vec::new(),
+        }
+    }
+
+    ///
+    /// # Summarize
+    ///
+    /// Build one report by project and language, in the order of the results
+    ///
+    #[must_use]
+    pub fn summarize(results: &[TaskResult]) -> Vec<Self> {
+        let mut reports: Vec<Self> = Vec::new();
+        for result in results {
+            let language: String = result.language.to_string();
+            let index: usize = if let Some(index) = reports.iter().position(|report| {
+                report.project.eq(&result.project) && report.language.eq(&language)
+            }) {
+                index
+            } else {
+                let mut report: Self = Self::new();
+                report.project = result.project.to_string();
+                report.language = language;
+                report.code = SUCCESS;
+                reports.push(report);
+                reports.len() - 1
+            };
+            let report: &mut Self = &mut reports[index];
+            report.record(result.task.category, result.status);
+            if result.status.is_failure() || result.status.eq(&TaskStatus::Cancelled) {
+                report.code = FAILURE;
+            }
+            if result.cached {
+                report.cached += 1;
+            }
+            report.duration += result.duration;
+            report.tasks.push(result.clone());
+        }
+        reports
+    }
+
+    ///
+    /// # Merge
+    ///
+    /// Replace the reports checked again, matched by project and language,
+    /// the new reports are appended
+    ///
+    #[must_use]
+    pub fn merge(mut reports: Vec<Self>, fresh: Vec<Self>) -> Vec<Self> {
+        for report in fresh {
+            if let Some(index) = reports.iter().position(|old| {
+                old.project.eq(&report.project) && old.language.eq(&report.language)
+            }) {
+                reports[index] = report;
+            } else {
+                reports.push(report);
+            }
+        }
+        reports
+    }
+
+    #[must_use]
+    #[doc = "The language, prefixed by the project outside the repository root"]
+    pub fn name(&self) -> String {
+        if self.project.eq(ROOT_PROJECT) {
+            self.language.to_string()
+        } else {
+            format!("{}/{}", self.project, self.language)
+        }
+    }
+
+    ///
+    /// # Record
+    ///
+    /// Update the column matching the task category.
+    /// A column stay invalid as soon as one of its tasks has failed,
+    /// timed out or missing a tool as soon as one of its tasks has been stopped or could not start,
+    /// skipped or cancelled as soon as one of its tasks has not been executed until the end,
+    /// and flaky as soon as one of its tasks has passed on a retry.
+    /// It is unaffected only when none of its tasks has been executed.
+    ///
+    pub fn record(&mut self, category: TaskCategory, status: TaskStatus) {
+        let (column, valid, not_valid): (&mut String, &str, &str) = match category {
+            TaskCategory::Structure => (
+                &mut self.project_structure,
+                PROJECT_STRUCTURE_VALID,
+                PROJECT_STRUCTURE_NOT_VALID,
+            ),
+            TaskCategory::License => (
+                &mut self.licenses,
+                PROJECT_LICENSE_VALID,
+                PROJECT_LICENSE_NOT_VALID,
+            ),
+            TaskCategory::Dependencies => (
+                &mut self.dependencies,
+                BUILD_DEPENDENCIES_VALID,
+                BUILD_DEPENDENCIES_NOT_VALID,
+            ),
+            TaskCategory::Audit => (&mut self.audit, AUDIT_VALID, AUDIT_NOT_VALID),
+            TaskCategory::Test => (&mut self.test, TESTS_RESULT_VALID, TESTS_RESULTS_NOT_VALID),
+            TaskCategory::Format => (
+                &mut self.standard,
+                RESPECT_OF_STANDARD_VALID,
+                RESPECT_OF_STANDARD_NOT_VALID,
+            ),
+            TaskCategory::Docs => (&mut self.documented, DOCUMENTED_VALID, DOCUMENTED_NOT_VALID),
+            TaskCategory::Outdated => (&mut self.outdated, OUTDATED_VALID, OUTDATED_NOT_VALID),
+            TaskCategory::Lint => (&mut self.lint, CODE_VALID, CODE_NOT_VALID),
+            TaskCategory::Custom => return,
+        };
+        let failed: bool = column.as_str().eq(not_valid.to_uppercase().as_str());
+        let stopped: bool = column.as_str().eq(TIMEOUT) || column.as_str().eq(MISSING_TOOL);
+        let incomplete: bool = column.as_str().eq(SKIPPED) || column.as_str().eq(CANCELLED);
+        match status {
+            TaskStatus::Fail => *column = not_valid.to_uppercase(),
+            TaskStatus::Timeout if !failed => *column = TIMEOUT.to_string(),
+            TaskStatus::Missing if !failed => *column = MISSING_TOOL.to_string(),
+            TaskStatus::Skipped if !failed && !stopped => *column = SKIPPED.to_string(),
+            TaskStatus::Cancelled if !failed && !stopped => *column = CANCELLED.to_string(),
+            TaskStatus::Flaky if !failed && !stopped && !incomplete => {
+                *column = FLAKY.to_string();
+            }
+            TaskStatus::Pass if !failed && !stopped && !incomplete && column.as_str().ne(FLAKY) => {
+                *column = valid.to_uppercase();
+            }
+            TaskStatus::Unaffected if column.as_str().eq(NOT_CHECKED) => {
+                *column = UNAFFECTED.to_string();
+            }
+            TaskStatus::Pass
+            | TaskStatus::Skipped
+            | TaskStatus::Unaffected
+            | TaskStatus::Timeout
+            | TaskStatus::Cancelled
+            | TaskStatus::Flaky
+            | TaskStatus::Missing => {}
         }
     }
 }
@@ -220,12 +863,26 @@ pub fn init() -> ExitCode {
         return ExitCode::SUCCESS;
     }
 
+    let all: Vec<String> = Support::new().all();
+    let detected: Vec<Detection> = detect(Path::new("."));
+    let preselected: Vec<usize> = detected
+        .iter()
+        .filter(|detection| detection.is_certain())
+        .filter_map(|detection| {
+            all.iter()
+                .position(|name| name.eq(&detection.language.to_string()))
+        })
+        .collect();
     let languages: Vec<String> = MultiSelect::new(
         "Select the languages used in your project :",
-        Support::new().all(),
+        all,
+    )
+    .with_default(&preselected)
+    .with_help_message(
+        "The detected languages are selected, use auto in tux.toml to detect them at each run",
     )
     .prompt()
-    .unwrap_or(Vec::from(["Rust".to_string()]));
+    .unwrap_or_else(|_| vec![AUTO.to_string()]);
     let strict = Confirm::new("Do you want to stop the script on the first failure ?")
         .with_default(false)
         .prompt()
@@ -242,15 +899,230 @@ strict = {strict}
             .collect::<Vec<_>>()
             .join(", "),
     );
-    assert!(execute!(stdout(), Show).is_ok());
+    show_cursor();
     if let Ok(mut conf) = File::create(tux) {
         assert!(conf.write_all(config_content.as_bytes()).is_ok());
         assert!(conf.sync_all().is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
+        clear();
         ok("The config has been generated successfully at tux.toml", 0);
         return ExitCode::SUCCESS;
     }
-    assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
+    clear();
     ko("Failed to generate config : /tux.toml", 0);
     ExitCode::FAILURE
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::Job;
+
+    fn task(name: &str, after: &[&str], needs: &[&str]) -> Task {
+        Task::new(name, TaskCategory::Custom, name, "true", "", "")
+            .with_after(after)
+            .with_needs(needs)
+    }
+
+    #[test]
+    fn cycle_finds_the_tasks_waiting_on_each_other() {
+        let todo: Vec<Task> = vec![
+            task("format", &[], &[]),
+            task("lint", &["format"], &["test"]),
+            task("test", &["lint"], &[]),
+        ];
+        assert_eq!(
+            cycle(&todo),
+            Some(vec![
+                String::from("lint"),
+                String::from("test"),
+                String::from("lint")
+            ])
+        );
+    }
+
+    #[test]
+    fn cycle_accepts_a_shared_prerequisite() {
+        let todo: Vec<Task> = vec![
+            task("format", &[], &[]),
+            task("lint", &["format"], &[]),
+            task("test", &["format"], &["lint"]),
+            task("unknown", &["missing"], &[]),
+        ];
+        assert_eq!(cycle(&todo), None);
+    }
+
+    #[test]
+    fn validate_rejects_an_unknown_tasks_language() {
+        let config: Config =
+            toml::from_str("strict = false\n[tasks.Rsut.test]\ncommand = \"cargo test\"\n")
+                .expect("the configuration parses");
+        assert_eq!(
+            config.validate(),
+            Err(String::from(
+                "Invalid [tasks.Rsut] => Rsut is not a supported language"
+            ))
+        );
+    }
+
+    #[test]
+    fn overrides_accept_the_language_aliases() {
+        let config: Config =
+            toml::from_str("strict = false\n[tasks.\"C++\".test]\ncommand = \"ctest\"\n")
+                .expect("the configuration parses");
+        assert!(config.overrides(&Language::Cpp).is_some());
+        assert!(config.overrides(&Language::Rust).is_none());
+    }
+
+    fn config(text: &str) -> Config {
+        toml::from_str(format!("strict = false\n{text}").as_str())
+            .expect("the configuration parses")
+    }
+
+    #[test]
+    fn tasks_apply_the_overrides() {
+        let config: Config = config(
+            r#"timeout = 60
+[tasks.rust.test]
+command = "cargo nextest run"
+timeout = 10
+env = { RUST_BACKTRACE = "1" }
+[tasks.rust.format]
+disabled = true
+[tasks.rust.spell]
+command = ["typos", "--format", "brief"]
+after = ["format", "lint"]
+"#,
+        );
+        assert_eq!(config.validate(), Ok(()));
+        let todo: Vec<Task> = config.tasks(&Language::Rust);
+        let find = |name: &str| todo.iter().find(|task| task.name.eq(name));
+        let test: &Task = find("test").expect("the test task is kept");
+        assert_eq!(test.command_line(), "cargo nextest run");
+        assert_eq!(test.timeout, Some(10));
+        assert_eq!(
+            test.env.get("RUST_BACKTRACE").map(String::as_str),
+            Some("1")
+        );
+        assert!(find("format").is_none());
+        assert!(todo
+            .iter()
+            .all(|task| !task.prerequisites().iter().any(|name| name.eq(&"format"))));
+        let spell: &Task = find("spell").expect("the spell task is added");
+        assert_eq!(spell.category, TaskCategory::Custom);
+        assert_eq!(spell.command_line(), "typos --format brief");
+        assert_eq!(spell.after, vec![String::from("lint")]);
+        assert_eq!(spell.timeout, Some(60));
+        assert_eq!(find("doc").and_then(|task| task.timeout), Some(60));
+    }
+
+    #[test]
+    fn validate_rejects_the_bad_task_fields() {
+        assert_eq!(
+            config("[tasks.Rust.spell]\ntitle = \"Spelling\"\n").validate(),
+            Err(String::from(
+                "The Rust task spell is not a built-in task and has no command"
+            ))
+        );
+        assert_eq!(
+            config("[tasks.Rust.test]\ncommand = \" \"\n").validate(),
+            Err(String::from("The Rust task test has an empty command"))
+        );
+        assert_eq!(
+            config("[tasks.Rust.test]\nafter = [\"missing\"]\n").validate(),
+            Err(String::from(
+                "The Rust task test waits for the unknown task missing"
+            ))
+        );
+        assert!(toml::from_str::<Config>(
+            "strict = false\n[tasks.Rust.test]\ncategory = \"nope\"\n"
+        )
+        .is_err());
+    }
+
+    #[doc = "The column of the report after recording the statuses of its tasks in order"]
+    fn column(category: TaskCategory, statuses: &[TaskStatus]) -> String {
+        let mut report: Report = Report::new();
+        for status in statuses {
+            report.record(category, *status);
+        }
+        match category {
+            TaskCategory::Test => report.test,
+            TaskCategory::Lint => report.lint,
+            _ => report.audit,
+        }
+    }
+
+    #[test]
+    fn record_keeps_the_worst_status_of_a_column() {
+        use TaskStatus::{Cancelled, Fail, Flaky, Missing, Pass, Skipped, Timeout};
+        assert_eq!(column(TaskCategory::Test, &[Pass]), TESTS_RESULT_VALID);
+        assert_eq!(column(TaskCategory::Test, &[Pass, Flaky, Pass]), FLAKY);
+        assert_eq!(column(TaskCategory::Test, &[Flaky, Skipped, Pass]), SKIPPED);
+        assert_eq!(
+            column(TaskCategory::Test, &[Cancelled, Timeout, Skipped]),
+            TIMEOUT
+        );
+        assert_eq!(
+            column(TaskCategory::Test, &[Missing, Cancelled]),
+            MISSING_TOOL
+        );
+        assert_eq!(
+            column(TaskCategory::Test, &[Timeout, Fail, Pass, Missing]),
+            TESTS_RESULTS_NOT_VALID
+        );
+        assert_eq!(column(TaskCategory::Audit, &[Pass, Fail]), AUDIT_NOT_VALID);
+    }
+
+    #[test]
+    fn record_marks_a_column_unaffected_while_nothing_ran() {
+        use TaskStatus::{Fail, Pass, Unaffected};
+        assert_eq!(column(TaskCategory::Lint, &[]), NOT_CHECKED);
+        assert_eq!(
+            column(TaskCategory::Lint, &[Unaffected, Unaffected]),
+            UNAFFECTED
+        );
+        assert_eq!(column(TaskCategory::Lint, &[Unaffected, Pass]), CODE_VALID);
+        assert_eq!(column(TaskCategory::Lint, &[Pass, Unaffected]), CODE_VALID);
+        let mut report: Report = Report::new();
+        report.record(TaskCategory::Custom, Fail);
+        assert_eq!(report.test, NOT_CHECKED);
+        assert_eq!(report.lint, NOT_CHECKED);
+    }
+
+    #[test]
+    fn summarize_groups_the_results_by_project_and_language() {
+        let result = |project: &str, language: Language, category: TaskCategory, status| {
+            let job: Job = Job {
+                project: project.to_string(),
+                language,
+                task: Task::new("task", category, "", "true", "", ""),
+            };
+            let mut result: TaskResult = job.result(status);
+            result.duration = Duration::from_secs(2);
+            result
+        };
+        let mut cached: TaskResult =
+            result(".", Language::Go, TaskCategory::Lint, TaskStatus::Pass);
+        cached.cached = true;
+        let reports: Vec<Report> = Report::summarize(&[
+            result(".", Language::Rust, TaskCategory::Test, TaskStatus::Pass),
+            cached,
+            result(
+                "web",
+                Language::Rust,
+                TaskCategory::Test,
+                TaskStatus::Cancelled,
+            ),
+            result(".", Language::Rust, TaskCategory::Lint, TaskStatus::Pass),
+        ]);
+        let names: Vec<String> = reports.iter().map(Report::name).collect();
+        assert_eq!(names, vec!["Rust", "Go", "web/Rust"]);
+        assert_eq!(reports[0].code, SUCCESS);
+        assert_eq!(reports[0].tasks.len(), 2);
+        assert_eq!(reports[0].duration, Duration::from_secs(4));
+        assert_eq!(reports[0].lint, CODE_VALID);
+        assert_eq!(reports[1].cached, 1);
+        assert_eq!(reports[2].code, FAILURE);
+        assert_eq!(reports[2].test, CANCELLED);
+    }
+}
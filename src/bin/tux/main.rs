@@ -1,34 +1,37 @@
 #![allow(clippy::multiple_crate_versions)]
-use clap::{ArgMatches, Command};
-use cli_table::{print_stdout, WithTitle};
-use crossterm::{
-    cursor::{Hide, MoveTo, Show},
-    execute,
-    terminal::{Clear, ClearType},
-};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, ColorChoice, WithTitle};
 use std::{
-    fs::{create_dir_all, read_to_string, File},
-    io::stdout,
-    process::{Command as Tux, ExitCode},
-    thread::sleep,
-    time::Duration,
+    fs::{create_dir_all, read_to_string, remove_file, rename, write, File},
+    io::Error,
+    path::{Path, PathBuf},
+    process::{Command as Tux, ExitCode, ExitStatus, Stdio},
+    time::{Duration, Instant},
 };
 use zuu::{
-    ask::{
-        init, Config, Report, AUDIT_NOT_VALID, AUDIT_VALID, BUILD_DEPENDENCIES_NOT_VALID,
-        BUILD_DEPENDENCIES_VALID, CODE_NOT_VALID, CODE_VALID, DOCUMENTED_NOT_VALID,
-        DOCUMENTED_VALID, FAILURE, OUTDATED_NOT_VALID, OUTDATED_VALID, OUTPUT_FILES,
-        PROJECT_LICENSE_NOT_VALID, PROJECT_LICENSE_VALID, PROJECT_STRUCTURE_NOT_VALID,
-        PROJECT_STRUCTURE_VALID, RESPECT_OF_STANDARD_NOT_VALID, RESPECT_OF_STANDARD_VALID, SUCCESS,
-        TESTS_RESULTS_NOT_VALID, TESTS_RESULT_VALID,
+    ask::{init, Config, Profile, Report, FAILURE, SUCCESS},
+    cache,
+    diff::{self, Diff, SLOWDOWN_THRESHOLD},
+    doctor::{self, MissingTool, Tool},
+    flaky::{self, FlakyTask},
+    history::{self, Run, Trend},
+    hook::{self, Hook},
+    interrupt::{cancelled, handle_signals, INTERRUPTED},
+    output::{
+        clear, colored, configure, excerpt, fit, hide_cursor, ko, ok, show_cursor, skip, timestamp,
+        verbose, wait_for, waiting, ColorMode, Tee, POLL_INTERVAL,
+    },
+    report::{
+        failure_output, junit, save, slowest, Timing, JSON_REPORT, JUNIT_REPORT, SLOWEST_TASKS,
     },
-    output::waiting,
     runner::create_zuu,
-    support::{Language, Support},
-    BASH_TASK, CPP_TASK, CRYSTAL_TASK, C_TASK, DART_TASK, D_TASK, ELIXIR_TASK, FISH_TASK,
-    FSHARP_TASK, GO_TASK, HASKELL_TASK, JAVA_TASK, KOTLIN_TASK, LUA_TASK, NIM_TASK, NODEJS_TASK,
-    OBJC_TASK, PERL_TASK, PHP_TASK, PYTHON_TASK, RUBY_TASK, RUST_TASK, R_TASK, SCALA_TASK,
-    SWIFT_TASK, ZSH_TASK,
+    sarif::{rendered, sarif, Parser, SARIF_REPORT},
+    scheduler::{Executor, Job, Scheduler},
+    since::{changed_since, is_affected, widened},
+    staged::{staged_files, Snapshot},
+    support::{language_of, Language},
+    task::{Task, TaskResult, TaskStatus},
+    watch::{affected, watch},
 };
 
 #[doc = "command line options"]
@@ -46,12 +49,142 @@ by using the strict mode. Tux also helps you set up source tracking by creating
         .about(
             "Tux checks your project’s source code quality by running various validation tasks."
         )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .global(true)
+                .value_parser(["table", "json", "junit", "sarif"])
+                .default_value("table")
+                .help("Print the final report as a table, as json, as junit xml or the linter findings as sarif"),
+        )
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .global(true)
+                .value_parser(["auto", "always", "never"])
+                .default_value("auto")
+                .help("Color the output, auto colors a terminal unless NO_COLOR is set"),
+        )
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .short('j')
+                .global(true)
+                .value_parser(value_parser!(usize))
+                .help("Number of tasks to run at the same time"),
+        )
+        .arg(
+            Arg::new("staged")
+                .long("staged")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Check the content of the index, the unstaged changes are hidden during the run"),
+        )
+        .arg(
+            Arg::new("since")
+                .long("since")
+                .global(true)
+                .value_name("REF")
+                .help("Execute only the tasks of the languages changed since the git reference"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .short('v')
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Print the output of the tasks while they run, line by line"),
+        )
+        .arg(
+            Arg::new("tail")
+                .long("tail")
+                .global(true)
+                .value_name("LINES")
+                .value_parser(value_parser!(usize))
+                .default_value("20")
+                .help("Number of output lines printed under the report for each failed task, 0 to hide them"),
+        )
+        .arg(
+            Arg::new("no-cache")
+                .long("no-cache")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Execute the tasks with inputs even when they have not changed, the cache is refreshed"),
+        )
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .global(true)
+                .help("Execute only the tasks of a profile defined in tux.toml"),
+        )
         .subcommand(
             Command::new("strict")
                 .about("Exit on the first failure during validation")
                 .subcommand_required(false),
         )
-        .subcommand(Command::new("watch").about("enable watch mode"))
+        .subcommand(
+            Command::new("hook")
+                .about("Run tux from the git hooks")
+                .subcommand_required(true)
+                .subcommand(hook_command(
+                    "install",
+                    "Write the hooks, the existing hooks are executed first (pre-commit by default)",
+                ))
+                .subcommand(hook_command(
+                    "uninstall",
+                    "Remove the hooks and restore the previous ones (all by default)",
+                )),
+        )
+        .subcommand(Command::new("watch").about("Check again the languages of the changed files"))
+        .subcommand(
+            Command::new("history")
+                .about("Show the status streaks, the duration trends and the first failures of the tasks")
+                .arg(
+                    Arg::new("runs")
+                        .long("runs")
+                        .short('n')
+                        .value_parser(value_parser!(usize))
+                        .default_value("20")
+                        .help("Number of recorded runs to look at"),
+                ),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Compare the statuses, the durations and the linter findings of two runs")
+                .arg(
+                    Arg::new("before")
+                        .value_parser(value_parser!(u64))
+                        .help("Number of the first run, the second to last by default"),
+                )
+                .arg(
+                    Arg::new("after")
+                        .value_parser(value_parser!(u64))
+                        .help("Number of the second run, the last by default"),
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .value_parser(value_parser!(u64))
+                        .default_value("20")
+                        .help("Percentage of slowdown reported"),
+                ),
+        )
+        .subcommand(
+            Command::new("doctor")
+                .about("Check that the tools needed by the configured tasks are installed"),
+        )
+        .subcommand(
+            Command::new("flaky")
+                .about("List the tasks passing on a retry most often")
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .short('n')
+                        .value_parser(value_parser!(usize))
+                        .default_value("10")
+                        .help("Number of tasks to list"),
+                ),
+        )
         .subcommand(
             Command::new("init")
                 .about("Initialize the source tracking by creating a tux.toml configuration file")
@@ -60,11 +193,90 @@ by using the strict mode. Tux also helps you set up source tracking by creating
         .get_matches()
 }
 
-pub fn zuu() -> (Vec<Report>, i32) {
-    let reports: Vec<Report> = check_source_code();
+#[doc = "A hook subcommand with the hook choice flags"]
+fn hook_command(name: &'static str, about: &'static str) -> Command {
+    Command::new(name)
+        .about(about)
+        .arg(
+            Arg::new("pre-commit")
+                .long("pre-commit")
+                .action(ArgAction::SetTrue)
+                .help("The hook executed by git commit"),
+        )
+        .arg(
+            Arg::new("pre-push")
+                .long("pre-push")
+                .action(ArgAction::SetTrue)
+                .help("The hook executed by git push"),
+        )
+}
+
+#[doc = "The run options given on the command line"]
+pub struct Options {
+    pub jobs: Option<usize>,
+    pub format: String,
+    pub color: ColorMode,
+    pub profile: Option<String>,
+    pub staged: bool,
+    pub since: Option<String>,
+    pub cache: bool,
+    pub verbose: bool,
+    pub tail: usize,
+}
+
+impl Options {
+    #[must_use]
+    pub fn new(app: &ArgMatches) -> Self {
+        Self {
+            jobs: app.get_one::<usize>("jobs").copied(),
+            format: app
+                .get_one::<String>("format")
+                .cloned()
+                .unwrap_or_else(|| String::from("table")),
+            color: app
+                .get_one::<String>("color")
+                .and_then(|color| color.parse().ok())
+                .unwrap_or_default(),
+            profile: app.get_one::<String>("profile").cloned(),
+            staged: app.get_flag("staged"),
+            since: app.get_one::<String>("since").cloned(),
+            cache: !app.get_flag("no-cache"),
+            verbose: app.get_flag("verbose"),
+            tail: app.get_one::<usize>("tail").copied().unwrap_or(20),
+        }
+    }
+
+    #[doc = "Check if the report is printed as json"]
+    fn json(&self) -> bool {
+        self.format.eq("json")
+    }
+
+    #[doc = "Check if the report is printed as junit xml"]
+    fn junit(&self) -> bool {
+        self.format.eq("junit")
+    }
+
+    #[doc = "Check if the linter findings are printed as sarif"]
+    fn sarif(&self) -> bool {
+        self.format.eq("sarif")
+    }
+
+    #[doc = "Check if the progress is hidden to keep stdout machine-readable"]
+    fn quiet(&self) -> bool {
+        self.json() || self.junit() || self.sarif()
+    }
+}
+
+pub fn zuu(options: &Options) -> (Vec<Report>, i32) {
+    publish(check_source_code(options, |_| true))
+}
 
-    assert!(execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).is_ok());
-    assert!(print_stdout(reports.with_title()).is_ok());
+#[doc = "Save the report files and find the exit code"]
+fn publish(reports: Vec<Report>) -> (Vec<Report>, i32) {
+    let json: String = zuu::report::json(&reports).unwrap_or_default();
+    assert!(save(JSON_REPORT, &json).is_ok());
+    assert!(save(JUNIT_REPORT, &junit(&reports)).is_ok());
+    assert!(save(SARIF_REPORT, &sarif(&reports).unwrap_or_default()).is_ok());
     for report in &reports {
         if report.code.eq(&1) {
             return (reports, FAILURE);
@@ -73,306 +285,652 @@ pub fn zuu() -> (Vec<Report>, i32) {
     (reports, SUCCESS)
 }
 
-fn report(reports: Vec<Report>) {
-    assert!(execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show).is_ok());
-    assert!(print_stdout(reports.with_title()).is_ok());
-    assert!(execute!(stdout(), Show).is_ok());
+fn report(reports: &[Report], options: &Options) {
+    if options.json() {
+        println!("{}", zuu::report::json(reports).unwrap_or_default());
+        return;
+    }
+    if options.junit() {
+        print!("{}", junit(reports));
+        return;
+    }
+    if options.sarif() {
+        println!("{}", sarif(reports).unwrap_or_default());
+        return;
+    }
+    clear();
+    assert!(print_stdout(reports.with_title().color_choice(choice())).is_ok());
+    let timings: Vec<Timing> = slowest(reports, SLOWEST_TASKS);
+    if !timings.is_empty() {
+        println!("\nSlowest tasks");
+        assert!(print_stdout(timings.with_title().color_choice(choice())).is_ok());
+    }
+    if !options.verbose && options.tail > 0 {
+        for result in reports
+            .iter()
+            .flat_map(|report| &report.tasks)
+            .filter(|result| result.status.is_failure())
+        {
+            let (path, lines): (&Path, String) = failure_output(result, options.tail);
+            if !lines.trim().is_empty() {
+                excerpt(
+                    format!("{} {}, {}:", result.id(), result.status, path.display()).as_str(),
+                    &lines,
+                );
+            }
+        }
+    }
+    show_cursor();
+}
+
+#[doc = "The table colors, following the output colors"]
+fn choice() -> ColorChoice {
+    if colored() {
+        ColorChoice::Always
+    } else {
+        ColorChoice::Never
+    }
+}
+
+#[doc = "Print the flakiest tasks of the history"]
+fn flakiest(app: &ArgMatches) -> ExitCode {
+    let limit: usize = app.get_one::<usize>("limit").copied().unwrap_or(10);
+    let tasks: Vec<FlakyTask> = flaky::flakiest(&flaky::load(), limit);
+    clear();
+    if tasks.is_empty() {
+        ok("No flaky task has been recorded", 0);
+    } else {
+        assert!(print_stdout(tasks.with_title().color_choice(choice())).is_ok());
+    }
+    show_cursor();
+    ExitCode::SUCCESS
+}
+
+#[doc = "Print the streaks and the trends of the tasks of the last runs"]
+fn trends(app: &ArgMatches) -> ExitCode {
+    let window: usize = app.get_one::<usize>("runs").copied().unwrap_or(20);
+    let runs: Vec<Run> = history::load();
+    clear();
+    match (runs.first(), runs.last()) {
+        (Some(first), Some(last)) => {
+            println!(
+                "{} runs recorded from {} to {}, the last {} are shown",
+                runs.len(),
+                first.started,
+                last.finished,
+                window.min(runs.len())
+            );
+            let tasks: Vec<Trend> = history::trends(&runs, window);
+            assert!(print_stdout(tasks.with_title().color_choice(choice())).is_ok());
+        }
+        _ => ok("No run has been recorded", 0),
+    }
+    show_cursor();
+    ExitCode::SUCCESS
+}
+
+#[doc = "Print what changed between two recorded runs"]
+fn compare(app: &ArgMatches) -> ExitCode {
+    let runs: Vec<Run> = history::load();
+    let threshold: f64 = app
+        .get_one::<u64>("threshold")
+        .map_or(SLOWDOWN_THRESHOLD, |percent| *percent as f64 / 100.0);
+    let last: Option<&Run> = runs.last();
+    let (before, after) = match (
+        app.get_one::<u64>("before").copied(),
+        app.get_one::<u64>("after").copied(),
+    ) {
+        (Some(before), Some(after)) => (diff::find(&runs, before), diff::find(&runs, after)),
+        (Some(before), None) => (diff::find(&runs, before), last),
+        _ => (runs.len().checked_sub(2).and_then(|i| runs.get(i)), last),
+    };
+    clear();
+    let (Some(before), Some(after)) = (before, after) else {
+        ko(
+            "The runs to compare have not been recorded, see tux history",
+            0,
+        );
+        show_cursor();
+        return ExitCode::FAILURE;
+    };
+    println!(
+        "Comparing the run {} with the run {}",
+        history::describe(before),
+        history::describe(after)
+    );
+    let changes: Diff = diff::compare(before, after, threshold);
+    if changes.is_empty() {
+        ok("No status, duration or finding has changed", 0);
+    }
+    if !changes.statuses.is_empty() {
+        println!("\nStatus changes");
+        assert!(print_stdout(changes.statuses.with_title().color_choice(choice())).is_ok());
+    }
+    if !changes.slowdowns.is_empty() {
+        println!("\nSlower tasks");
+        assert!(print_stdout(changes.slowdowns.with_title().color_choice(choice())).is_ok());
+    }
+    if !changes.findings.is_empty() {
+        println!("\nLinter findings");
+        assert!(print_stdout(changes.findings.with_title().color_choice(choice())).is_ok());
+    }
+    show_cursor();
+    ExitCode::SUCCESS
+}
+
+#[doc = "Print the tools needed by the tasks, with their version, and how to install the missing ones"]
+fn doctor() -> ExitCode {
+    let (found, missing): (Vec<Tool>, Vec<MissingTool>) = doctor::diagnose(&plan(&load_config()));
+    clear();
+    if !found.is_empty() {
+        assert!(print_stdout(found.with_title().color_choice(choice())).is_ok());
+    }
+    show_cursor();
+    if missing.is_empty() {
+        ok("All the tools needed by the tasks are installed", 0);
+        return ExitCode::SUCCESS;
+    }
+    println!("\nMissing tools");
+    assert!(print_stdout(missing.with_title().color_choice(choice())).is_ok());
+    ExitCode::FAILURE
 }
+
 #[must_use]
 pub fn main() -> ExitCode {
-    assert!(execute!(
-        stdout(),
-        Clear(crossterm::terminal::ClearType::All),
-        Hide,
-        MoveTo(0, 0)
-    )
-    .is_ok());
-    assert!(create_zuu().is_ok());
     let app: ArgMatches = tux();
+    let options: Options = Options::new(&app);
+    configure(options.color, options.verbose);
+    if !options.quiet() {
+        clear();
+        hide_cursor();
+    }
+    if let Some(hook) = app.subcommand_matches("hook") {
+        return hooks(hook);
+    }
+    assert!(create_zuu().is_ok());
     if app.subcommand_matches("init").is_some() && app.subcommand_matches("watch").is_none() {
         return init();
     }
-
+    if let Some(flaky) = app.subcommand_matches("flaky") {
+        return flakiest(flaky);
+    }
+    if let Some(history) = app.subcommand_matches("history") {
+        return trends(history);
+    }
+    if let Some(diff) = app.subcommand_matches("diff") {
+        return compare(diff);
+    }
+    if app.subcommand_matches("doctor").is_some() {
+        return doctor();
+    }
+    handle_signals();
+    if let Some(reference) = &options.since {
+        if let Err(error) = changed_since(reference) {
+            show_cursor();
+            eprintln!("Invalid reference {reference} => {error}");
+            return ExitCode::FAILURE;
+        }
+    }
     if app.subcommand_matches("watch").is_some() {
-        loop {
-            report(zuu().0);
-            sleep(Duration::from_secs(60));
+        return watching(&options);
+    }
+    let snapshot: Option<Snapshot> = if options.staged {
+        match Snapshot::take() {
+            Ok(snapshot) => Some(snapshot),
+            Err(error) => {
+                ko(
+                    format!("Failed to hide the unstaged changes => {error}").as_str(),
+                    0,
+                );
+                show_cursor();
+                return ExitCode::FAILURE;
+            }
         }
+    } else {
+        None
+    };
+    let r = zuu(&options);
+    if let Some(Err(error)) = snapshot.map(Snapshot::restore) {
+        show_cursor();
+        eprintln!("{error}");
     }
-    let r = zuu();
-    report(r.0);
-    if r.1.eq(&FAILURE) {
+    report(&r.0, &options);
+    if cancelled() {
+        interrupted()
+    } else if r.1.eq(&FAILURE) {
         ExitCode::FAILURE
     } else {
         ExitCode::SUCCESS
     }
 }
 
+#[doc = "The exit code of an interrupted run"]
+fn interrupted() -> ExitCode {
+    ExitCode::from(u8::try_from(INTERRUPTED).unwrap_or(1))
+}
+
+///
+/// # Hooks
+///
+/// Install or uninstall the chosen git hooks
+///
+fn hooks(app: &ArgMatches) -> ExitCode {
+    let (install, matches): (bool, &ArgMatches) = match app.subcommand() {
+        Some(("install", matches)) => (true, matches),
+        Some((_, matches)) => (false, matches),
+        None => return ExitCode::FAILURE,
+    };
+    let mut chosen: Vec<Hook> = Hook::all()
+        .into_iter()
+        .filter(|hook| matches.get_flag(hook.to_string().as_str()))
+        .collect();
+    if chosen.is_empty() {
+        chosen = if install {
+            vec![Hook::PreCommit]
+        } else {
+            Hook::all().to_vec()
+        };
+    }
+    let mut code: ExitCode = ExitCode::SUCCESS;
+    for (x, hook) in chosen.into_iter().enumerate() {
+        if install {
+            match hook::install(hook) {
+                Ok(path) => ok(
+                    format!("The {hook} hook has been written at {}", path.display()).as_str(),
+                    x,
+                ),
+                Err(error) => {
+                    ko(
+                        format!("Failed to write the {hook} hook => {error}").as_str(),
+                        x,
+                    );
+                    code = ExitCode::FAILURE;
+                }
+            }
+        } else {
+            match hook::uninstall(hook) {
+                Ok(true) => ok(format!("The {hook} hook has been removed").as_str(), x),
+                Ok(false) => skip(
+                    format!("The {hook} hook was not installed by tux").as_str(),
+                    x,
+                ),
+                Err(error) => {
+                    ko(
+                        format!("Failed to remove the {hook} hook => {error}").as_str(),
+                        x,
+                    );
+                    code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    show_cursor();
+    code
+}
+
+///
+/// # Watching
+///
+/// Check everything, then check again the languages of the changed files.
+/// The last report stays on screen between the runs.
+///
+fn watching(options: &Options) -> ExitCode {
+    let mut reports: Vec<Report> = zuu(options).0;
+    report(&reports, options);
+    let status = watch(Path::new("."), |changed| {
+        let fresh: Vec<Report> = match affected(&load_config().projects(), changed) {
+            None => check_source_code(options, |_| true),
+            Some(keys) if keys.is_empty() => return,
+            Some(keys) => check_source_code(options, |job| {
+                keys.iter()
+                    .any(|(project, language)| job.project.eq(project) && job.language.eq(language))
+            }),
+        };
+        reports = publish(Report::merge(std::mem::take(&mut reports), fresh)).0;
+        report(&reports, options);
+    });
+    show_cursor();
+    if let Err(error) = status {
+        eprintln!("Failed to watch the project => {error}");
+        return ExitCode::FAILURE;
+    }
+    if cancelled() {
+        return interrupted();
+    }
+    ExitCode::SUCCESS
+}
+
 #[doc = "load user configuration"]
 pub fn load_config() -> Config {
     if let Ok(config) = read_to_string("tux.toml") {
         if let Ok(tux) = toml::from_str::<Config>(&config) {
+            if let Err(error) = tux.validate() {
+                show_cursor();
+                panic!("Invalid tux.toml => {error}");
+            }
             return tux;
         }
     }
-    assert!(execute!(stdout(), Show).is_ok());
+    show_cursor();
     panic!("Failed to load config => run tux init");
 }
 
-pub fn contains_dangerous_chars(command: &str) -> bool {
-    let dangerous_chars = [
-        "/",  // Root directory expansion
-        "./", // Execution
-        ";",  // Command separator
-        "&",  // Background execution
-        "|",  // Pipe to chain commands
-        "`",  // Command substitution
-        "$(", // Start of command substitution (alternative to `)
-        "${", // Variable substitution (can be used for injection)
-        ">",  // Output redirection
-        "<",  // Input redirection
-        ">>", // Append output redirection
-        "<<", // Multiple input redirection
-        "*",  // Wildcard (can be exploited)
-        "?",  // Wildcard (can be exploited)
-        "[",  // Start of a regex or character class (can be used in commands)
-        "]",  // End of a regex or character class
-        "~",  // Home directory expansion
-        "^",  // Substitution in some shells
-        "!",  // Command history in some shells
-        "\\", // Escape character
-        "||", // Logical OR
-        "&&", // Logical AND
-        "(",  // Open parentheses (used for subshells or grouping)
-        ")",  // Close parentheses
-        "#",  // Comment in many shells (can hide parts of commands)
-    ];
-    dangerous_chars.iter().any(|&c| command.contains(c))
-}
-
 ///
 /// # Panics
 ///
-/// On failed parse config or crossterm faillure
+/// On failed parse config or crossterm faillure.
+/// The `--since` reference is checked by main, every task runs when git fails on it later.
 ///
 #[must_use]
-pub fn check_source_code() -> Vec<Report> {
+pub fn check_source_code(options: &Options, keep: impl Fn(&Job) -> bool) -> Vec<Report> {
     let config: Config = load_config();
-    let mut reports: Vec<Report> = Vec::new();
-    let to_check: Vec<String> = config.languages;
-    for lang in &Support::new().supported() {
-        if to_check.contains(&lang.to_string()) {
-            if let Ok(report) = source_code_verify(lang, config.strict) {
-                reports.push(report);
+    let started: String = timestamp();
+    let profile: Option<&Profile> =
+        match options.profile.as_deref().map(|name| config.profile(name)) {
+            Some(Err(error)) => {
+                show_cursor();
+                panic!("Invalid profile => {error}");
             }
+            Some(Ok(profile)) => profile,
+            None => None,
+        };
+    let mut todo: Vec<Job> = plan(&config)
+        .into_iter()
+        .filter(|job| profile.is_none_or(|profile| profile.keeps(&job.task)))
+        .filter(keep)
+        .collect();
+    let mut unaffected: Vec<Job> = Vec::new();
+    if options.staged {
+        let (targeted, untouched) = target_files(todo, &staged_files().unwrap_or_default(), &[]);
+        todo = targeted;
+        unaffected = untouched;
+    }
+    if let Some(Ok(changed)) = options.since.as_deref().map(changed_since) {
+        if let Some(keys) = affected(&config.projects(), &changed) {
+            let whole: Vec<(String, Language)> = widened(&config.projects(), &changed);
+            let (affected, others): (Vec<Job>, Vec<Job>) = todo
+                .into_iter()
+                .partition(|job| is_affected(job, &keys) || is_affected(job, &whole));
+            let existing: Vec<PathBuf> = changed.into_iter().filter(|file| file.exists()).collect();
+            let (targeted, untouched) = target_files(affected, &existing, &whole);
+            todo = targeted;
+            unaffected.extend(others.into_iter().chain(untouched));
         }
     }
-    reports
+    for job in &todo {
+        assert!(create_dir_all(job.directory().join("stderr")).is_ok());
+        assert!(create_dir_all(job.directory().join("stdout")).is_ok());
+    }
+    let scheduler: Scheduler = options
+        .jobs
+        .or(config.jobs)
+        .map_or_else(Scheduler::default, Scheduler::new)
+        .strict(config.strict);
+    let terminal: Terminal = Terminal {
+        quiet: options.quiet(),
+        cache: options.cache,
+    };
+    if !terminal.quiet {
+        fit(todo.len());
+        clear();
+    }
+    let mut results: Vec<TaskResult> = scheduler.run(&todo, &terminal);
+    if !unaffected.is_empty() {
+        let order: Vec<Job> = plan(&config);
+        results.extend(
+            unaffected
+                .iter()
+                .map(|job| job.result(TaskStatus::Unaffected)),
+        );
+        results.sort_by_key(|result| {
+            order.iter().position(|job| {
+                job.project.eq(&result.project)
+                    && job.language.eq(&result.language)
+                    && job.task.name.eq(&result.task.name)
+            })
+        });
+    }
+    assert!(flaky::record(&results).is_ok());
+    assert!(history::record(&started, &timestamp(), &results).is_ok());
+    Report::summarize(&results)
 }
 
-fn source_code_verify(l: &Language, strict: bool) -> std::io::Result<Report> {
-    assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-    let mut results: Vec<bool> = Vec::new();
+#[doc = "Execute the tasks with a spinner on the task line"]
+struct Terminal {
+    #[doc = "Execute without drawing the progress"]
+    quiet: bool,
+    #[doc = "Replay the cached results of the unchanged tasks"]
+    cache: bool,
+}
 
-    let todo = match l {
-        Language::Rust => RUST_TASK,
-        Language::Go => GO_TASK,
-        Language::D => D_TASK,
-        Language::Python => PYTHON_TASK,
-        Language::Php => PHP_TASK,
-        Language::Unknown => RUST_TASK,
-        Language::JavaScript => NODEJS_TASK,
-        Language::TypeScript => NODEJS_TASK,
-        Language::Java => JAVA_TASK,
-        Language::Kotlin => KOTLIN_TASK,
-        Language::Swift => SWIFT_TASK,
-        Language::Scala => SCALA_TASK,
-        Language::Ruby => RUBY_TASK,
-        Language::Perl => PERL_TASK,
-        Language::R => R_TASK,
-        Language::Haskell => HASKELL_TASK,
-        Language::Lua => LUA_TASK,
-        Language::ObjectiveC => OBJC_TASK,
-        Language::C => C_TASK,
-        Language::Cpp => CPP_TASK,
-        Language::Nim => NIM_TASK,
-        Language::Crystal => CRYSTAL_TASK,
-        Language::FSharp => FSHARP_TASK,
-        Language::Dart => DART_TASK,
-        Language::Elixir => ELIXIR_TASK,
-        Language::Bash => BASH_TASK,
-        Language::Zsh => ZSH_TASK,
-        Language::Fish => FISH_TASK,
-    };
-    assert!(create_dir_all(format!("zuu/{l}")).is_ok());
-    assert!(create_dir_all(format!("zuu/{l}/stderr")).is_ok());
-    assert!(create_dir_all(format!("zuu/{l}/stdout")).is_ok());
-    let mut waiting_line: usize = 0;
-    let mut ret: Report = Report::new();
-    ret.language = l.to_string();
-    for (index, command) in todo.iter().enumerate() {
-        waiting_line = index;
-        if contains_dangerous_chars(command.1) {
-            assert!(waiting(
-                (
-                    format!(
-                        "Stopped bedore task {}: {}/9. Dangerous command founded",
-                        command.0,
-                        index + 1,
-                    ),
-                    "Ok let's go".to_string(),
-                    "Oops".to_string()
-                ),
-                Tux::new("sleep").arg("10"),
-                waiting_line
-            )
-            .is_ok());
-            assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-            break;
+impl Executor for Terminal {
+    fn execute(&self, index: usize, job: &Job) -> TaskResult {
+        source_code_verify(index, job, self.quiet, self.cache)
+    }
+
+    fn skip(&self, index: usize, job: &Job, failed: &[&Job]) -> TaskResult {
+        if self.quiet {
+            return job.result(TaskStatus::Skipped);
         }
-        let data: (String, String, String) = (
-            command.0.to_string(), // title
-            command.2.to_string(), // success
-            command.3.to_string(), // failure
-        );
-        let report_error: bool = waiting(
-            data,
-            Tux::new("sh")
-                .arg("-c")
-                .arg(command.1)
-                .stderr(
-                    File::create(
-                        format!(
-                            "zuu/{l}/stderr/{}",
-                            OUTPUT_FILES.get(index).unwrap_or(&"default")
-                        )
-                        .as_str(),
-                    )
-                    .expect(""),
-                )
-                .stdout(
-                    File::create(
-                        format!(
-                            "zuu/{l}/stdout/{}",
-                            OUTPUT_FILES.get(index).unwrap_or(&"default")
-                        )
-                        .as_str(),
-                    )
-                    .expect("msg"),
-                ),
+        let names: Vec<&str> = failed.iter().map(|need| need.task.name.as_str()).collect();
+        skip(
+            format!(
+                "{} skipped, needs {}",
+                job.label(&job.task.title),
+                names.join(", ")
+            )
+            .as_str(),
             index,
-        )
-        .is_err();
-        results.push(report_error);
-        match index {
-            0 => {
-                ret.project_structure = if report_error {
-                    PROJECT_STRUCTURE_NOT_VALID.to_uppercase()
-                } else {
-                    PROJECT_STRUCTURE_VALID.to_uppercase()
-                }
-            }
-            1 => {
-                ret.licenses = if report_error {
-                    PROJECT_LICENSE_NOT_VALID.to_uppercase()
-                } else {
-                    PROJECT_LICENSE_VALID.to_uppercase()
-                }
-            }
-            2 => {
-                ret.dependencies = if report_error {
-                    BUILD_DEPENDENCIES_NOT_VALID.to_uppercase()
-                } else {
-                    BUILD_DEPENDENCIES_VALID.to_uppercase()
-                }
-            }
-            3 => {
-                ret.audit = if report_error {
-                    AUDIT_NOT_VALID.to_uppercase()
-                } else {
-                    AUDIT_VALID.to_uppercase()
-                }
-            }
-            4 => {
-                ret.test = if report_error {
-                    TESTS_RESULTS_NOT_VALID.to_uppercase()
-                } else {
-                    TESTS_RESULT_VALID.to_uppercase()
-                }
-            }
-            5 => {
-                ret.standard = if report_error {
-                    RESPECT_OF_STANDARD_NOT_VALID.to_uppercase()
-                } else {
-                    RESPECT_OF_STANDARD_VALID.to_uppercase()
-                }
+        );
+        job.result(TaskStatus::Skipped)
+    }
+}
+
+///
+/// # Target files
+///
+/// Give the files of their language to the tasks accepting file arguments,
+/// except to the whole projects and languages.
+/// The tasks without file of their language are returned apart
+///
+fn target_files(
+    todo: Vec<Job>,
+    changed: &[PathBuf],
+    whole: &[(String, Language)],
+) -> (Vec<Job>, Vec<Job>) {
+    let mut untouched: Vec<Job> = Vec::new();
+    let targeted: Vec<Job> = todo
+        .into_iter()
+        .filter_map(|mut job| {
+            if !job.task.files
+                || whole
+                    .iter()
+                    .any(|(project, language)| job.project.eq(project) && job.language.eq(language))
+            {
+                return Some(job);
             }
-            6 => {
-                ret.documented = if report_error {
-                    DOCUMENTED_NOT_VALID.to_uppercase()
-                } else {
-                    DOCUMENTED_VALID.to_uppercase()
-                }
+            let root: PathBuf = job.task.cwd.clone().unwrap_or_default();
+            let files: Vec<PathBuf> = changed
+                .iter()
+                .filter(|file| language_of(file).is_some_and(|language| language.eq(&job.language)))
+                .filter_map(|file| file.strip_prefix(&root).ok())
+                .map(Path::to_path_buf)
+                .collect();
+            if files.is_empty() {
+                untouched.push(job);
+                return None;
             }
-            7 => {
-                ret.outdated = if report_error {
-                    OUTDATED_NOT_VALID.to_uppercase()
-                } else {
-                    OUTDATED_VALID.to_uppercase()
-                }
+            job.task = job.task.targeting(&files);
+            Some(job)
+        })
+        .collect();
+    (targeted, untouched)
+}
+
+#[doc = "All the tasks of the configured projects and languages"]
+fn plan(config: &Config) -> Vec<Job> {
+    let mut todo: Vec<Job> = Vec::new();
+    for project in config.projects() {
+        for language in &project.languages {
+            for task in config.tasks(language) {
+                todo.push(Job {
+                    project: project.name.to_string(),
+                    language: *language,
+                    task: if project.is_root() {
+                        task
+                    } else {
+                        task.within(&project.root)
+                    },
+                });
             }
-            8 => {
-                ret.lint = if report_error {
-                    CODE_NOT_VALID.to_uppercase()
-                } else {
-                    CODE_VALID.to_uppercase()
-                }
+        }
+    }
+    todo
+}
+
+fn source_code_verify(index: usize, job: &Job, quiet: bool, cache: bool) -> TaskResult {
+    let task: &Task = &job.task;
+    let data: (String, String, String) = (
+        job.label(&task.title),
+        job.label(&task.success),
+        job.label(&task.failure),
+    );
+    if let Some((tool, message)) = doctor::missing(task) {
+        let _ = File::create(job.stdout());
+        let _ = write(job.stderr(), format!("{message}\n"));
+        if !quiet {
+            ko(
+                format!("{} ({tool} is not installed)", data.2).as_str(),
+                index,
+            );
+        }
+        return job.result(TaskStatus::Missing);
+    }
+    let key: Option<String> = cache::key(task);
+    if let Some(hit) = key
+        .as_deref()
+        .filter(|_| cache)
+        .and_then(|key| cache::replay(key, job))
+    {
+        if !quiet {
+            if hit.status.is_success() {
+                ok(format!("{} (cached)", data.1).as_str(), index);
+            } else {
+                ko(format!("{} (cached)", data.2).as_str(), index);
             }
-            _ => {}
         }
-        if strict && report_error.eq(&true) {
-            ret.code = FAILURE;
-            assert!(waiting(
-                (
-                    format!("Exiting {} (strict mode): {}/9.", command.0, index + 1),
-                    format!("Exiting the {l} test"),
-                    format!("Exiting the {l} test"),
-                ),
-                Tux::new("sleep").arg("10"),
-                waiting_line
-            )
-            .is_ok());
-            assert!(execute!(stdout(), Clear(ClearType::All), Hide).is_ok());
-            break;
+        return hit;
+    }
+    for retry in 1..=task.retries {
+        for file in job.attempt_outputs(retry) {
+            let _ = remove_file(file);
         }
     }
-    if results.contains(&true) {
-        ret.code = FAILURE;
-        assert!(waiting(
-            (
-                format!("Exit code failure for {l} test"),
-                format!("Exiting the {l} test"),
-                format!("Exiting the {l} test"),
-            ),
-            Tux::new("sleep").arg("10"),
-            waiting_line + 1
-        )
-        .is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-        Ok(ret)
-    } else {
-        ret.code = SUCCESS;
-        assert!(waiting(
-            (
-                format!("Exit code success for {l} test"),
-                format!("Exiting the {l} test"),
-                format!("Exiting the {l} test"),
-            ),
-            Tux::new("sleep").arg("10"),
-            waiting_line + 1
-        )
-        .is_ok());
-        assert!(execute!(stdout(), Clear(ClearType::All)).is_ok());
-        Ok(ret)
+    let unwritable = |error: Error| {
+        if !quiet {
+            ko(format!("{} => {error}", data.2).as_str(), index);
+        }
+        job.result(TaskStatus::Fail)
+    };
+    let mut result: TaskResult =
+        attempt(index, job, quiet, data.clone()).unwrap_or_else(unwritable);
+    let mut attempts: u32 = 1;
+    let mut duration: Duration = result.duration;
+    let mut backoff: Duration = Duration::from_secs(task.backoff);
+    while result.status.is_failure() && attempts <= task.retries && pause(backoff) {
+        for (from, to) in [job.stdout(), job.stderr()]
+            .into_iter()
+            .zip(job.attempt_outputs(attempts))
+        {
+            let _ = rename(from, to);
+        }
+        attempts += 1;
+        backoff *= 2;
+        let title: String = format!("{} (attempt {attempts}/{})", data.0, task.retries + 1);
+        result = attempt(index, job, quiet, (title, data.1.clone(), data.2.clone()))
+            .unwrap_or_else(unwritable);
+        duration += result.duration;
+        if result.status.is_success() {
+            result.status = TaskStatus::Flaky;
+        }
+    }
+    result.attempts = attempts;
+    result.duration = duration;
+    if let Some(key) = key {
+        let _ = cache::store(&key, &result);
+    }
+    result
+}
+
+#[doc = "Wait before a retry, false when the run is cancelled"]
+fn pause(delay: Duration) -> bool {
+    let start: Instant = Instant::now();
+    while start.elapsed() < delay && !cancelled() {
+        std::thread::sleep(POLL_INTERVAL);
     }
+    !cancelled()
+}
+
+///
+/// # Attempt
+///
+/// Execute the task once, a command failing to start is a failed task
+///
+/// # Errors
+///
+/// When the output files can not be created
+///
+fn attempt(
+    index: usize,
+    job: &Job,
+    quiet: bool,
+    data: (String, String, String),
+) -> Result<TaskResult, Error> {
+    let task: &Task = &job.task;
+    let mut cmd: Tux = task.to_command();
+    let stderr: File = File::create(job.stderr())?;
+    let stdout: File = File::create(job.stdout())?;
+    let tee: Option<Tee> = if verbose() {
+        cmd.stderr(Stdio::piped()).stdout(Stdio::piped());
+        let tee: Tee = Tee::new(job.id(), stdout, stderr);
+        Some(if Parser::detect(task).eq(&Some(Parser::Clippy)) {
+            tee.with_render(rendered)
+        } else {
+            tee
+        })
+    } else {
+        cmd.stderr(stderr).stdout(stdout);
+        None
+    };
+    let timeout: Option<Duration> = task.timeout.map(Duration::from_secs);
+    let start: Instant = Instant::now();
+    let exit: std::io::Result<Option<ExitStatus>> = if quiet {
+        wait_for(&mut cmd, timeout, tee)
+    } else {
+        waiting(data, &mut cmd, index, timeout, tee)
+    };
+    let duration: Duration = start.elapsed();
+    Ok(match exit {
+        Ok(None) => TaskResult {
+            duration,
+            ..job.result(if cancelled() {
+                TaskStatus::Cancelled
+            } else {
+                TaskStatus::Timeout
+            })
+        },
+        Ok(Some(exit)) => TaskResult {
+            exit_code: exit.code(),
+            duration,
+            ..job.result(if exit.success() {
+                TaskStatus::Pass
+            } else {
+                TaskStatus::Fail
+            })
+        },
+        Err(_) => TaskResult {
+            duration,
+            ..job.result(TaskStatus::Fail)
+        },
+    })
 }
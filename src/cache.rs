@@ -0,0 +1,202 @@
+use crate::output::{bounded_output, VERSION_TIMEOUT};
+use crate::scheduler::Job;
+use crate::task::{Task, TaskResult, TaskStatus};
+use glob::glob;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::env::var;
+use std::fmt::Write as _;
+use std::fs::{copy, create_dir_all, read, read_to_string, write};
+use std::io::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, PoisonError};
+
+#[doc = "The directory storing the cached results, one directory by key"]
+pub const CACHE_DIRECTORY: &str = "zuu/cache";
+
+#[doc = "The file of an entry holding the status, written last"]
+pub const ENTRY_FILE: &str = "entry.json";
+
+#[doc = "The variables of the environment changing the executed tools"]
+pub const RELEVANT_ENV: [&str; 1] = ["PATH"];
+
+#[doc = "The version output of the programs by directory, queried once by run"]
+static VERSIONS: Mutex<BTreeMap<(String, PathBuf), Vec<u8>>> = Mutex::new(BTreeMap::new());
+
+#[derive(Serialize, Deserialize)]
+#[doc = "The stored result of a task"]
+struct Entry {
+    status: TaskStatus,
+    exit_code: Option<i32>,
+}
+
+///
+/// # Version
+///
+/// The output of `<program> --version`, empty when it can not be executed or does not answer in time.
+/// The scripts of the project are never executed, their content is returned instead.
+///
+fn version(program: &str, cwd: &Path) -> Vec<u8> {
+    if program.contains('/') {
+        return read(cwd.join(program)).unwrap_or_default();
+    }
+    let key: (String, PathBuf) = (program.to_string(), cwd.to_path_buf());
+    if let Some(output) = VERSIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&key)
+    {
+        return output.clone();
+    }
+    let mut command: Command = Command::new(program);
+    command.arg("--version");
+    if !cwd.as_os_str().is_empty() {
+        command.current_dir(cwd);
+    }
+    let output: Vec<u8> = bounded_output(&mut command, VERSION_TIMEOUT)
+        .ok()
+        .flatten()
+        .map(|output| output.stdout)
+        .unwrap_or_default();
+    VERSIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(key, output.clone());
+    output
+}
+
+#[doc = "The files matched by the input patterns, sorted and relative to the task directory"]
+fn inputs(task: &Task, cwd: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = task
+        .inputs
+        .iter()
+        .filter_map(|pattern| glob(cwd.join(pattern).to_string_lossy().as_ref()).ok())
+        .flat_map(|paths| paths.filter_map(Result::ok))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+///
+/// # Key
+///
+/// The hash of the command, of its environment, of the tool version
+/// and of the files matched by the task inputs.
+/// None when the task declares no input.
+///
+#[must_use]
+pub fn key(task: &Task) -> Option<String> {
+    if task.inputs.is_empty() {
+        return None;
+    }
+    let cwd: PathBuf = task.cwd.clone().unwrap_or_default();
+    let mut hasher: Sha256 = Sha256::new();
+    let mut field = |bytes: &[u8]| {
+        hasher.update(bytes.len().to_le_bytes());
+        hasher.update(bytes);
+    };
+    field(task.program.as_bytes());
+    for argument in task.arguments() {
+        field(argument.as_bytes());
+    }
+    field(cwd.to_string_lossy().as_bytes());
+    for (name, value) in &task.env {
+        field(format!("{name}={value}").as_bytes());
+    }
+    for name in RELEVANT_ENV {
+        field(format!("{name}={}", var(name).unwrap_or_default()).as_bytes());
+    }
+    field(&version(&task.executable(), &cwd));
+    for file in inputs(task, &cwd) {
+        field(
+            file.strip_prefix(&cwd)
+                .unwrap_or(&file)
+                .to_string_lossy()
+                .as_bytes(),
+        );
+        field(&read(&file).ok()?);
+    }
+    Some(
+        hasher
+            .finalize()
+            .iter()
+            .fold(String::new(), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            }),
+    )
+}
+
+///
+/// # Replay
+///
+/// Copy the stored outputs of the key into the job output files,
+/// the stored result is returned marked as cached
+///
+#[must_use]
+pub fn replay(key: &str, job: &Job) -> Option<TaskResult> {
+    let directory: PathBuf = Path::new(CACHE_DIRECTORY).join(key);
+    let entry: Entry =
+        serde_json::from_str(&read_to_string(directory.join(ENTRY_FILE)).ok()?).ok()?;
+    copy(directory.join("stdout.txt"), job.stdout()).ok()?;
+    copy(directory.join("stderr.txt"), job.stderr()).ok()?;
+    Some(TaskResult {
+        exit_code: entry.exit_code,
+        cached: true,
+        ..job.result(entry.status)
+    })
+}
+
+///
+/// # Store
+///
+/// Keep the outputs and the status of a task exited by itself,
+/// the interrupted tasks are never stored and a flaky task is stored as passed
+///
+/// # Errors
+///
+/// On no write rights
+///
+pub fn store(key: &str, result: &TaskResult) -> Result<(), Error> {
+    if result.exit_code.is_none() || !result.status.is_executed() {
+        return Ok(());
+    }
+    let directory: PathBuf = Path::new(CACHE_DIRECTORY).join(key);
+    create_dir_all(&directory)?;
+    copy(&result.stdout, directory.join("stdout.txt"))?;
+    copy(&result.stderr, directory.join("stderr.txt"))?;
+    let entry: Entry = Entry {
+        status: if result.status.eq(&TaskStatus::Flaky) {
+            TaskStatus::Pass
+        } else {
+            result.status
+        },
+        exit_code: result.exit_code,
+    };
+    write(
+        directory.join(ENTRY_FILE),
+        serde_json::to_string(&entry).map_err(Error::other)?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    #[test]
+    fn version_reads_the_scripts_instead_of_running_them() {
+        let directory: PathBuf =
+            std::env::temp_dir().join(format!("tux-cache-{}", std::process::id()));
+        create_dir_all(&directory).expect("the directory is created");
+        let script: &str = "#!/bin/sh\ntouch ran\n";
+        write(directory.join("script.sh"), script).expect("the script is written");
+        assert_eq!(version("./script.sh", &directory), script.as_bytes());
+        assert!(!directory.join("ran").exists());
+        remove_dir_all(&directory).expect("the directory is removed");
+    }
+}
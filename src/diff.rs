@@ -0,0 +1,240 @@
+use crate::history::{Record, Run};
+use crate::report::elapsed;
+use crate::sarif::Finding;
+use cli_table::{format::Justify, Color, Table};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+#[doc = "A task slower than this ratio of its previous duration is reported, 0.2 is 20%"]
+pub const SLOWDOWN_THRESHOLD: f64 = 0.2;
+
+#[doc = "Slowdowns shorter than this number of seconds are noise and are not reported"]
+pub const SLOWDOWN_MINIMUM: f64 = 0.5;
+
+#[derive(Table)]
+#[doc = "A task whose status changed between the two runs"]
+pub struct StatusChange {
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "BEFORE", justify = "Justify::Left", color = "Color::White")]
+    pub before: String,
+    #[table(title = "AFTER", justify = "Justify::Left", color = "Color::White")]
+    pub after: String,
+}
+
+#[derive(Table)]
+#[doc = "A task slower in the second run"]
+pub struct Slowdown {
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "BEFORE", justify = "Justify::Right", color = "Color::White")]
+    pub before: String,
+    #[table(title = "AFTER", justify = "Justify::Right", color = "Color::White")]
+    pub after: String,
+    #[table(title = "CHANGE", justify = "Justify::Right", color = "Color::White")]
+    pub change: String,
+}
+
+#[derive(Table)]
+#[doc = "A linter finding appearing or disappearing in the second run"]
+pub struct FindingChange {
+    #[table(title = "CHANGE", justify = "Justify::Left", color = "Color::White")]
+    pub change: String,
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "RULE", justify = "Justify::Left", color = "Color::White")]
+    pub rule: String,
+    #[table(title = "LOCATION", justify = "Justify::Left", color = "Color::White")]
+    pub location: String,
+    #[table(title = "MESSAGE", justify = "Justify::Left", color = "Color::White")]
+    pub message: String,
+}
+
+#[doc = "The differences between two runs"]
+pub struct Diff {
+    pub statuses: Vec<StatusChange>,
+    pub slowdowns: Vec<Slowdown>,
+    pub findings: Vec<FindingChange>,
+}
+
+impl Diff {
+    #[must_use]
+    #[doc = "Check if the two runs gave the same results"]
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty() && self.slowdowns.is_empty() && self.findings.is_empty()
+    }
+}
+
+#[must_use]
+#[doc = "The run recorded with this number"]
+pub fn find(runs: &[Run], id: u64) -> Option<&Run> {
+    runs.iter().find(|run| run.id.eq(&id))
+}
+
+///
+/// # Compare
+///
+/// The status changes, the slowdowns past the threshold and the new and fixed findings
+/// of the tasks of the second run against the first one
+///
+#[must_use]
+pub fn compare(before: &Run, after: &Run, threshold: f64) -> Diff {
+    let previous: BTreeMap<&str, &Record> = before
+        .tasks
+        .iter()
+        .map(|record| (record.task.as_str(), record))
+        .collect();
+    let mut diff: Diff = Diff {
+        statuses: Vec::new(),
+        slowdowns: Vec::new(),
+        findings: Vec::new(),
+    };
+    for record in &after.tasks {
+        let Some(old) = previous.get(record.task.as_str()) else {
+            diff.statuses.push(StatusChange {
+                task: record.task.clone(),
+                before: String::from("-"),
+                after: record.status.to_string(),
+            });
+            continue;
+        };
+        if old.status.ne(&record.status) {
+            diff.statuses.push(StatusChange {
+                task: record.task.clone(),
+                before: old.status.to_string(),
+                after: record.status.to_string(),
+            });
+        }
+        if old.timed()
+            && record.timed()
+            && old.duration > 0.0
+            && record.duration - old.duration >= SLOWDOWN_MINIMUM
+            && (record.duration - old.duration) / old.duration > threshold
+        {
+            diff.slowdowns.push(Slowdown {
+                task: record.task.clone(),
+                before: elapsed(&Duration::from_secs_f64(old.duration)),
+                after: elapsed(&Duration::from_secs_f64(record.duration)),
+                change: format!(
+                    "{:+.0}%",
+                    (record.duration - old.duration) * 100.0 / old.duration
+                ),
+            });
+        }
+        if old.status.is_executed() && record.status.is_executed() {
+            changes(
+                &record.task,
+                &old.findings,
+                &record.findings,
+                &mut diff.findings,
+            );
+        }
+    }
+    for old in &before.tasks {
+        if !after.tasks.iter().any(|record| record.task.eq(&old.task)) {
+            diff.statuses.push(StatusChange {
+                task: old.task.clone(),
+                before: old.status.to_string(),
+                after: String::from("-"),
+            });
+        }
+    }
+    diff
+}
+
+#[doc = "A finding is the same while its rule, its file and its message are, its line may move"]
+fn identity(finding: &Finding) -> (&str, &str, &str) {
+    (&finding.rule, &finding.file, &finding.message)
+}
+
+#[doc = "Push the findings only found after as new and the ones only found before as fixed"]
+fn changes(task: &str, before: &[Finding], after: &[Finding], into: &mut Vec<FindingChange>) {
+    let mut remaining: BTreeMap<(&str, &str, &str), usize> = BTreeMap::new();
+    for finding in before {
+        *remaining.entry(identity(finding)).or_default() += 1;
+    }
+    let mut fixed: Vec<&Finding> = Vec::new();
+    let mut added: Vec<&Finding> = Vec::new();
+    for finding in after {
+        match remaining.get_mut(&identity(finding)) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => added.push(finding),
+        }
+    }
+    for finding in before {
+        if let Some(count) = remaining.get_mut(&identity(finding)) {
+            if *count > 0 {
+                *count -= 1;
+                fixed.push(finding);
+            }
+        }
+    }
+    let change = |label: &str, finding: &Finding| FindingChange {
+        change: label.to_string(),
+        task: task.to_string(),
+        rule: finding.rule.clone(),
+        location: format!("{}:{}:{}", finding.file, finding.line, finding.column),
+        message: finding.message.clone(),
+    };
+    into.extend(added.into_iter().map(|finding| change("NEW", finding)));
+    into.extend(fixed.into_iter().map(|finding| change("FIXED", finding)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(rule: &str, line: usize) -> Finding {
+        Finding {
+            rule: rule.to_string(),
+            file: String::from("src/main.rs"),
+            line,
+            column: 1,
+            level: String::from("warning"),
+            message: format!("{rule} message"),
+        }
+    }
+
+    fn labels(changes: &[FindingChange]) -> Vec<(&str, &str)> {
+        changes
+            .iter()
+            .map(|change| (change.change.as_str(), change.rule.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn changes_ignore_the_moved_findings() {
+        let mut into: Vec<FindingChange> = Vec::new();
+        changes(
+            "Rust/lint",
+            &[finding("len_zero", 3), finding("needless_return", 8)],
+            &[finding("len_zero", 5), finding("unwrap_used", 9)],
+            &mut into,
+        );
+        assert_eq!(
+            labels(&into),
+            vec![("NEW", "unwrap_used"), ("FIXED", "needless_return")]
+        );
+        assert_eq!(into[0].location, "src/main.rs:9:1");
+    }
+
+    #[test]
+    fn changes_count_the_repeated_findings() {
+        let mut into: Vec<FindingChange> = Vec::new();
+        changes(
+            "Rust/lint",
+            &[finding("len_zero", 3), finding("len_zero", 7)],
+            &[finding("len_zero", 3)],
+            &mut into,
+        );
+        assert_eq!(labels(&into), vec![("FIXED", "len_zero")]);
+        into.clear();
+        changes(
+            "Rust/lint",
+            &[finding("len_zero", 3)],
+            &[finding("len_zero", 3), finding("len_zero", 7)],
+            &mut into,
+        );
+        assert_eq!(labels(&into), vec![("NEW", "len_zero")]);
+    }
+}
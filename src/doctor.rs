@@ -0,0 +1,246 @@
+use crate::output::{bounded_output, VERSION_TIMEOUT};
+use crate::scheduler::Job;
+use crate::task::Task;
+use cli_table::{format::Justify, Color, Table};
+use std::env::{split_paths, var_os};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[doc = "The cargo subcommands installed apart, with their executable"]
+pub const CARGO_PLUGINS: [(&str, &str); 4] = [
+    ("audit", "cargo-audit"),
+    ("outdated", "cargo-outdated"),
+    ("clippy", "cargo-clippy"),
+    ("fmt", "cargo-fmt"),
+];
+
+#[doc = "The words starting a shell command line without naming a program to find"]
+pub const SHELL_BUILTINS: [&str; 24] = [
+    "cd", "export", "set", "unset", "source", ".", "eval", "exec", "exit", "if", "for", "while",
+    "until", "case", "!", "(", "{", "[", "test", "true", "false", "echo", "command", "time",
+];
+
+#[doc = "How to install the tools used by the built-in tasks"]
+pub const INSTALL_HINTS: [(&str, &str); 27] = [
+    ("cargo", "curl https://sh.rustup.rs -sSf | sh"),
+    ("cargo-audit", "cargo install cargo-audit"),
+    ("cargo-outdated", "cargo install cargo-outdated"),
+    ("cargo-clippy", "rustup component add clippy"),
+    ("cargo-fmt", "rustup component add rustfmt"),
+    ("go", "https://go.dev/doc/install"),
+    (
+        "golangci-lint",
+        "go install github.com/golangci/golangci-lint/cmd/golangci-lint@latest",
+    ),
+    ("perltidy", "cpan Perl::Tidy"),
+    ("perlcritic", "cpan Perl::Critic"),
+    ("cpan-outdated", "cpan App::cpanoutdated"),
+    ("swiftformat", "brew install swiftformat"),
+    ("swiftlint", "brew install swiftlint"),
+    ("flake8", "pip install flake8"),
+    ("black", "pip install black"),
+    ("mypy", "pip install mypy"),
+    ("bandit", "pip install bandit"),
+    ("pytest", "pip install pytest"),
+    ("sphinx-build", "pip install sphinx"),
+    ("hlint", "cabal install hlint"),
+    ("hindent", "cabal install hindent"),
+    ("luacheck", "luarocks install luacheck"),
+    ("ldoc", "luarocks install ldoc"),
+    ("busted", "luarocks install busted"),
+    ("scalafmt", "cs install scalafmt"),
+    ("scalafix", "cs install scalafix"),
+    ("fantomas", "dotnet tool install -g fantomas"),
+    ("npm", "https://nodejs.org/en/download"),
+];
+
+#[derive(Table)]
+#[doc = "A tool found on the path"]
+pub struct Tool {
+    #[table(title = "TOOL", justify = "Justify::Left", color = "Color::White")]
+    pub name: String,
+    #[table(title = "VERSION", justify = "Justify::Left", color = "Color::White")]
+    pub version: String,
+    #[table(title = "PATH", justify = "Justify::Left", color = "Color::White")]
+    pub path: String,
+}
+
+#[derive(Table)]
+#[doc = "A tool needed by a task and not installed"]
+pub struct MissingTool {
+    #[table(title = "TOOL", justify = "Justify::Left", color = "Color::White")]
+    pub name: String,
+    #[table(title = "NEEDED BY", justify = "Justify::Left", color = "Color::White")]
+    pub tasks: String,
+    #[table(title = "INSTALL", justify = "Justify::Left", color = "Color::White")]
+    pub hint: String,
+}
+
+#[must_use]
+#[doc = "How to install a tool, the package manager of the system by default"]
+pub fn hint(tool: &str) -> String {
+    INSTALL_HINTS
+        .iter()
+        .find(|(name, _)| name.eq(&tool))
+        .map_or_else(
+            || format!("install {tool} with the package manager of the system"),
+            |(_, hint)| (*hint).to_string(),
+        )
+}
+
+#[doc = "The executables needed by a task, its program then the cargo plugin it runs"]
+fn tools(task: &Task) -> Vec<(String, Vec<String>)> {
+    let program: String = task.executable();
+    if program.is_empty()
+        || (task.shell && (program.contains('=') || SHELL_BUILTINS.contains(&program.as_str())))
+    {
+        return Vec::new();
+    }
+    let mut tools: Vec<(String, Vec<String>)> = vec![(program.to_string(), Vec::new())];
+    if program.eq("cargo") {
+        if let Some((subcommand, plugin)) = task.args.first().and_then(|first| {
+            CARGO_PLUGINS
+                .iter()
+                .find(|(subcommand, _)| subcommand.eq(first))
+        }) {
+            tools.push((plugin.to_string(), vec![subcommand.to_string()]));
+        }
+    }
+    tools
+}
+
+#[doc = "Check if the file can be executed"]
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+///
+/// # Resolve
+///
+/// The file executed for a program, searched in the path
+/// or from the task directory when the program is a path
+///
+#[must_use]
+pub fn resolve(program: &str, cwd: &Path) -> Option<PathBuf> {
+    if program.contains('/') {
+        return Some(cwd.join(program)).filter(|path| is_executable(path));
+    }
+    split_paths(&var_os("PATH")?).find_map(|directory| {
+        let path: PathBuf = directory.join(program);
+        if is_executable(&path) {
+            return Some(path);
+        }
+        #[cfg(windows)]
+        {
+            let exe: PathBuf = path.with_extension("exe");
+            if is_executable(&exe) {
+                return Some(exe);
+            }
+        }
+        None
+    })
+}
+
+#[doc = "The first line printed by `--version`, empty when the tool does not give it in time"]
+fn version(path: &Path, prefix: &[String]) -> String {
+    let mut command = Command::new(path);
+    command.args(prefix).arg("--version");
+    let Ok(Some(output)) = bounded_output(&mut command, VERSION_TIMEOUT) else {
+        return String::new();
+    };
+    if !output.status.success() {
+        return String::new();
+    }
+    [output.stdout, output.stderr]
+        .iter()
+        .flat_map(|bytes| {
+            String::from_utf8_lossy(bytes)
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect::<Vec<String>>()
+        })
+        .next()
+        .unwrap_or_default()
+}
+
+///
+/// # Missing
+///
+/// The first tool of the task not installed, with the message explaining how to install it
+///
+#[must_use]
+pub fn missing(task: &Task) -> Option<(String, String)> {
+    let cwd: PathBuf = task.cwd.clone().unwrap_or_default();
+    tools(task)
+        .into_iter()
+        .find(|(tool, _)| resolve(tool, &cwd).is_none())
+        .map(|(tool, _)| {
+            let message: String = format!("{tool} is not installed, {}", hint(&tool));
+            (tool, message)
+        })
+}
+
+///
+/// # Diagnose
+///
+/// The tools needed by the jobs found on the path, with their version,
+/// and the ones missing, with the tasks needing them.
+/// The scripts of the project are not executed to get their version.
+///
+#[must_use]
+pub fn diagnose(jobs: &[Job]) -> (Vec<Tool>, Vec<MissingTool>) {
+    let mut found: Vec<Tool> = Vec::new();
+    let mut missing: Vec<MissingTool> = Vec::new();
+    for job in jobs {
+        let cwd: PathBuf = job.task.cwd.clone().unwrap_or_default();
+        for (tool, prefix) in tools(&job.task) {
+            if let Some(absent) = missing.iter_mut().find(|absent| absent.name.eq(&tool)) {
+                absent.tasks.push_str(", ");
+                absent.tasks.push_str(&job.id());
+                continue;
+            }
+            if found.iter().any(|present| present.name.eq(&tool)) {
+                continue;
+            }
+            if let Some(path) = resolve(&tool, &cwd) {
+                let program: PathBuf = if prefix.is_empty() {
+                    path.clone()
+                } else {
+                    PathBuf::from(job.task.executable())
+                };
+                let version: String = if tool.contains('/') {
+                    String::new()
+                } else {
+                    version(&program, &prefix)
+                };
+                found.push(Tool {
+                    name: tool,
+                    version: if version.is_empty() {
+                        String::from("-")
+                    } else {
+                        version
+                    },
+                    path: path.to_string_lossy().to_string(),
+                });
+            } else {
+                missing.push(MissingTool {
+                    hint: hint(&tool),
+                    name: tool,
+                    tasks: job.id(),
+                });
+            }
+        }
+    }
+    (found, missing)
+}
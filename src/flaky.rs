@@ -0,0 +1,110 @@
+use crate::output::timestamp;
+use crate::task::{TaskResult, TaskStatus};
+use cli_table::{format::Justify, Color, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::Error;
+use std::path::Path;
+
+#[doc = "The flaky counts of the tasks, kept between the runs"]
+pub const FLAKY_HISTORY: &str = "zuu/history/flaky.json";
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[doc = "The executions of a task kept in the history"]
+pub struct Flakiness {
+    #[doc = "Number of executions, the cached results are not counted"]
+    pub runs: u64,
+    #[doc = "Number of executions passing on a retry"]
+    pub flaky: u64,
+    #[doc = "The time of the last flaky execution"]
+    pub last: Option<String>,
+}
+
+#[derive(Table)]
+#[doc = "A line of the flakiest tasks table"]
+pub struct FlakyTask {
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "FLAKY", justify = "Justify::Left", color = "Color::White")]
+    pub flaky: u64,
+    #[table(title = "RUNS", justify = "Justify::Left", color = "Color::White")]
+    pub runs: u64,
+    #[table(title = "RATE", justify = "Justify::Left", color = "Color::White")]
+    pub rate: String,
+    #[table(
+        title = "LAST FLAKY",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub last: String,
+}
+
+#[must_use]
+#[doc = "The flaky counts by task id, empty when nothing has been recorded"]
+pub fn load() -> BTreeMap<String, Flakiness> {
+    read_to_string(FLAKY_HISTORY)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+///
+/// # Record
+///
+/// Count the executions and the flaky results of a run in the history
+///
+/// # Errors
+///
+/// On no write rights
+///
+pub fn record(results: &[TaskResult]) -> Result<(), Error> {
+    let mut history: BTreeMap<String, Flakiness> = load();
+    let now: String = timestamp();
+    for result in results
+        .iter()
+        .filter(|result| result.status.is_executed() && !result.cached)
+    {
+        let entry: &mut Flakiness = history.entry(result.id()).or_default();
+        entry.runs += 1;
+        if result.status.eq(&TaskStatus::Flaky) {
+            entry.flaky += 1;
+            entry.last = Some(now.clone());
+        }
+    }
+    if let Some(directory) = Path::new(FLAKY_HISTORY).parent() {
+        create_dir_all(directory)?;
+    }
+    write(
+        FLAKY_HISTORY,
+        serde_json::to_string_pretty(&history).map_err(Error::other)?,
+    )
+}
+
+///
+/// # Flakiest
+///
+/// The tasks which have been flaky, the most flaky first
+///
+#[must_use]
+pub fn flakiest(history: &BTreeMap<String, Flakiness>, limit: usize) -> Vec<FlakyTask> {
+    let mut tasks: Vec<(&String, &Flakiness)> = history
+        .iter()
+        .filter(|(_, flakiness)| flakiness.flaky > 0)
+        .collect();
+    tasks.sort_by(|(_, a), (_, b)| b.flaky.cmp(&a.flaky).then(b.last.cmp(&a.last)));
+    tasks
+        .into_iter()
+        .take(limit)
+        .map(|(task, flakiness)| FlakyTask {
+            task: (*task).clone(),
+            flaky: flakiness.flaky,
+            runs: flakiness.runs,
+            rate: format!(
+                "{:.1}%",
+                flakiness.flaky as f64 * 100.0 / flakiness.runs.max(1) as f64
+            ),
+            last: flakiness.last.clone().unwrap_or_default(),
+        })
+        .collect()
+}
@@ -0,0 +1,341 @@
+use crate::report::elapsed;
+use crate::sarif::{findings, Finding};
+use crate::staged::revision;
+use crate::task::{TaskResult, TaskStatus};
+use cli_table::{format::Justify, Color, Table};
+use serde::{Deserialize, Serialize};
+use std::fs::{create_dir_all, read_to_string, write, OpenOptions};
+use std::io::{Error, Write};
+use std::path::Path;
+use std::time::Duration;
+
+#[doc = "The runs recorded one by line, the oldest first"]
+pub const HISTORY_FILE: &str = "zuu/history/runs.jsonl";
+
+#[doc = "Number of runs kept in the history, the oldest are removed"]
+pub const HISTORY_LIMIT: usize = 1000;
+
+#[doc = "A change of duration past this ratio of the average is shown as a trend"]
+pub const TREND_THRESHOLD: f64 = 0.1;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[doc = "A recorded run"]
+pub struct Run {
+    #[doc = "The number of the run, starting at 1"]
+    pub id: u64,
+    #[doc = "The commit checked out during the run, none outside of a git repository"]
+    pub commit: Option<String>,
+    pub branch: Option<String>,
+    pub started: String,
+    pub finished: String,
+    pub tasks: Vec<Record>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[doc = "The result of a task in a recorded run"]
+pub struct Record {
+    #[doc = "The task id, `<language>/<task>` or `<project>/<language>/<task>`"]
+    pub task: String,
+    pub status: TaskStatus,
+    #[doc = "Time spent by the task, in seconds"]
+    pub duration: f64,
+    #[serde(default)]
+    pub cached: bool,
+    #[doc = "The linter findings of the task, empty for the tasks without a known linter"]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<Finding>,
+}
+
+impl Record {
+    #[must_use]
+    #[doc = "Check if the duration has been measured during the run"]
+    pub fn timed(&self) -> bool {
+        self.status.is_executed() && !self.cached
+    }
+}
+
+#[derive(Table)]
+#[doc = "A line of the history table"]
+pub struct Trend {
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "LAST", justify = "Justify::Left", color = "Color::White")]
+    pub status: TaskStatus,
+    #[table(title = "STREAK", justify = "Justify::Left", color = "Color::White")]
+    pub streak: String,
+    #[table(title = "RUNS", justify = "Justify::Left", color = "Color::White")]
+    pub runs: usize,
+    #[table(title = "DURATION", justify = "Justify::Right", color = "Color::White")]
+    pub duration: String,
+    #[table(title = "TREND", justify = "Justify::Right", color = "Color::White")]
+    pub trend: String,
+    #[table(
+        title = "FAILING SINCE",
+        justify = "Justify::Left",
+        color = "Color::White"
+    )]
+    pub failing_since: String,
+}
+
+#[must_use]
+#[doc = "The recorded runs, the oldest first, the unreadable lines are ignored"]
+pub fn load() -> Vec<Run> {
+    read_to_string(HISTORY_FILE)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+///
+/// # Record
+///
+/// Append the results of a run to the history, with the commit and the branch checked out
+///
+/// # Errors
+///
+/// On no write rights
+///
+pub fn record(started: &str, finished: &str, results: &[TaskResult]) -> Result<Run, Error> {
+    let mut runs: Vec<Run> = load();
+    let run: Run = Run {
+        id: runs.last().map_or(1, |last| last.id + 1),
+        commit: revision(&["rev-parse", "HEAD"]),
+        branch: revision(&["rev-parse", "--abbrev-ref", "HEAD"]),
+        started: started.to_string(),
+        finished: finished.to_string(),
+        tasks: results
+            .iter()
+            .map(|result| Record {
+                task: result.id(),
+                status: result.status,
+                duration: result.duration.as_secs_f64(),
+                cached: result.cached,
+                findings: findings(result),
+            })
+            .collect(),
+    };
+    if let Some(directory) = Path::new(HISTORY_FILE).parent() {
+        create_dir_all(directory)?;
+    }
+    let line: String = serde_json::to_string(&run).map_err(Error::other)?;
+    if runs.len() < HISTORY_LIMIT {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_FILE)?;
+        writeln!(file, "{line}")?;
+        return Ok(run);
+    }
+    runs.drain(..=runs.len() - HISTORY_LIMIT);
+    let mut content: String = String::new();
+    for old in &runs {
+        content.push_str(&serde_json::to_string(old).map_err(Error::other)?);
+        content.push('\n');
+    }
+    content.push_str(&line);
+    content.push('\n');
+    write(HISTORY_FILE, content)?;
+    Ok(run)
+}
+
+#[must_use]
+#[doc = "The run described by its number, its date and its commit"]
+pub fn describe(run: &Run) -> String {
+    let mut text: String = format!("#{} {}", run.id, run.started);
+    if let Some(commit) = &run.commit {
+        text.push(' ');
+        text.push_str(&commit[..commit.len().min(7)]);
+    }
+    text
+}
+
+///
+/// # Trends
+///
+/// The status streak, the duration trend and the first failure of every task of the last runs.
+/// The last duration is compared to the average of the previous ones,
+/// the skipped, unaffected and cancelled results do not break a streak.
+///
+#[must_use]
+pub fn trends(runs: &[Run], window: usize) -> Vec<Trend> {
+    let runs: &[Run] = &runs[runs.len().saturating_sub(window)..];
+    let mut tasks: Vec<&String> = Vec::new();
+    for run in runs.iter().rev() {
+        for record in &run.tasks {
+            if !tasks.contains(&&record.task) {
+                tasks.push(&record.task);
+            }
+        }
+    }
+    tasks
+        .into_iter()
+        .filter_map(|task| {
+            let results: Vec<(&Run, &Record)> = runs
+                .iter()
+                .filter_map(|run| {
+                    run.tasks
+                        .iter()
+                        .find(|record| record.task.eq(task))
+                        .map(|record| (run, record))
+                })
+                .collect();
+            let (_, last) = results.last()?;
+            let decided: Vec<&(&Run, &Record)> = results
+                .iter()
+                .filter(|(_, record)| record.status.is_executed())
+                .collect();
+            let failing: Option<bool> =
+                decided.last().map(|(_, record)| record.status.is_failure());
+            let streak: Vec<&&(&Run, &Record)> = decided
+                .iter()
+                .rev()
+                .take_while(|(_, record)| Some(record.status.is_failure()).eq(&failing))
+                .collect();
+            let timed: Vec<f64> = results
+                .iter()
+                .filter(|(_, record)| record.timed())
+                .map(|(_, record)| record.duration)
+                .collect();
+            Some(Trend {
+                task: task.to_string(),
+                status: last.status,
+                streak: match failing {
+                    Some(true) => format!("{} failed", streak.len()),
+                    Some(false) => format!("{} passed", streak.len()),
+                    None => String::from("-"),
+                },
+                runs: results.len(),
+                duration: timed.last().map_or_else(
+                    || String::from("-"),
+                    |seconds| elapsed(&Duration::from_secs_f64(*seconds)),
+                ),
+                trend: trend(&timed),
+                failing_since: match (failing, streak.last()) {
+                    (Some(true), Some((run, _))) => describe(run),
+                    _ => String::from("-"),
+                },
+            })
+        })
+        .collect()
+}
+
+#[doc = "The change of the last duration against the average of the previous ones"]
+fn trend(durations: &[f64]) -> String {
+    let Some((last, previous)) = durations.split_last() else {
+        return String::from("-");
+    };
+    if previous.is_empty() {
+        return String::from("-");
+    }
+    let average: f64 = previous.iter().sum::<f64>() / previous.len() as f64;
+    if average <= 0.0 {
+        return String::from("-");
+    }
+    let change: f64 = (last - average) / average;
+    if change.abs() < TREND_THRESHOLD {
+        String::from("=")
+    } else {
+        format!("{:+.0}%", change * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(task: &str, status: TaskStatus, duration: f64) -> Record {
+        Record {
+            task: task.to_string(),
+            status,
+            duration,
+            cached: false,
+            findings: Vec::new(),
+        }
+    }
+
+    fn run(id: u64, commit: Option<&str>, tasks: Vec<Record>) -> Run {
+        Run {
+            id,
+            commit: commit.map(String::from),
+            branch: Some(String::from("main")),
+            started: format!("2026-01-0{id} 10:00:00"),
+            finished: format!("2026-01-0{id} 10:05:00"),
+            tasks,
+        }
+    }
+
+    fn trend_of<'a>(trends: &'a [Trend], task: &str) -> &'a Trend {
+        trends
+            .iter()
+            .find(|trend| trend.task.eq(task))
+            .expect("the task has a trend")
+    }
+
+    #[test]
+    fn trends_count_the_streak_since_the_first_failure() {
+        let runs: Vec<Run> = vec![
+            run(
+                1,
+                Some("0123456789"),
+                vec![
+                    record("rust/lint", TaskStatus::Pass, 1.0),
+                    record("rust/test", TaskStatus::Pass, 10.0),
+                ],
+            ),
+            run(
+                2,
+                Some("abcdef0123"),
+                vec![
+                    record("rust/lint", TaskStatus::Pass, 1.05),
+                    record("rust/test", TaskStatus::Fail, 10.0),
+                ],
+            ),
+            run(3, None, vec![record("rust/test", TaskStatus::Skipped, 0.0)]),
+            run(
+                4,
+                None,
+                vec![record("rust/test", TaskStatus::Timeout, 12.0)],
+            ),
+        ];
+        let trends: Vec<Trend> = trends(&runs, 10);
+        assert_eq!(trends[0].task, "rust/test");
+        let test: &Trend = trend_of(&trends, "rust/test");
+        assert_eq!(test.status, TaskStatus::Timeout);
+        assert_eq!(test.streak, "2 failed");
+        assert_eq!(test.runs, 4);
+        assert_eq!(test.duration, "12.0s");
+        assert_eq!(test.trend, "+20%");
+        assert_eq!(test.failing_since, "#2 2026-01-02 10:00:00 abcdef0");
+        let lint: &Trend = trend_of(&trends, "rust/lint");
+        assert_eq!(lint.streak, "2 passed");
+        assert_eq!(lint.trend, "=");
+        assert_eq!(lint.failing_since, "-");
+    }
+
+    #[test]
+    fn trends_keep_the_window_and_the_measured_durations() {
+        let mut cached: Record = record("go/test", TaskStatus::Pass, 0.01);
+        cached.cached = true;
+        let runs: Vec<Run> = vec![
+            run(1, None, vec![record("go/test", TaskStatus::Fail, 40.0)]),
+            run(2, None, vec![record("go/test", TaskStatus::Pass, 20.0)]),
+            run(
+                3,
+                None,
+                vec![cached, record("go/lint", TaskStatus::Unaffected, 0.0)],
+            ),
+        ];
+        let trends: Vec<Trend> = trends(&runs, 2);
+        let test: &Trend = trend_of(&trends, "go/test");
+        assert_eq!(test.runs, 2);
+        assert_eq!(test.streak, "2 passed");
+        assert_eq!(test.duration, "20.0s");
+        assert_eq!(test.trend, "-");
+        let lint: &Trend = trend_of(&trends, "go/lint");
+        assert_eq!(lint.status, TaskStatus::Unaffected);
+        assert_eq!(lint.streak, "-");
+        assert_eq!(lint.duration, "-");
+        assert_eq!(lint.trend, "-");
+    }
+}
@@ -0,0 +1,244 @@
+use crate::staged::revision;
+use std::env::current_exe;
+use std::fmt::Display;
+use std::fs::{read_to_string, remove_file, rename, write};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+#[doc = "The line marking the hooks written by tux"]
+pub const HOOK_MARKER: &str = "# installed by tux hook install";
+
+#[doc = "The suffix of the hooks found before the installation, they are executed first"]
+pub const CHAINED_SUFFIX: &str = "tux-chained";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[doc = "The git hooks running tux"]
+pub enum Hook {
+    PreCommit,
+    PrePush,
+}
+
+impl Display for Hook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hook::PreCommit => write!(f, "pre-commit"),
+            Hook::PrePush => write!(f, "pre-push"),
+        }
+    }
+}
+
+impl Hook {
+    #[must_use]
+    #[doc = "All the hooks"]
+    pub const fn all() -> [Self; 2] {
+        [Self::PreCommit, Self::PrePush]
+    }
+
+    #[must_use]
+    #[doc = "Find a hook by its git name"]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|hook| hook.to_string().eq(name))
+    }
+
+    #[must_use]
+    #[doc = "The hook found before the installation"]
+    pub fn chained(&self, directory: &Path) -> PathBuf {
+        directory.join(format!("{self}.{CHAINED_SUFFIX}"))
+    }
+
+    ///
+    /// # Script
+    ///
+    /// The hook running the chained hook first, then tux with the profile named after the hook.
+    /// The pre-commit hook checks the staged changes only.
+    ///
+    #[must_use]
+    pub fn script(&self, tux: &Path) -> String {
+        format!(
+            r#"#!/bin/sh
+{HOOK_MARKER}
+chained="$(dirname "$0")/{self}.{CHAINED_SUFFIX}"
+if [ -x "$chained" ]; then
+    "$chained" "$@" || exit $?
+fi
+exec {} --profile {self}{}
+"#,
+            shell_words::quote(tux.to_string_lossy().as_ref()),
+            if self.eq(&Self::PreCommit) {
+                " --staged"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+///
+/// # Hooks directory
+///
+/// The `core.hooksPath` directory when set, `.git/hooks` otherwise
+///
+/// # Errors
+///
+/// Outside of a git repository
+///
+pub fn hooks_directory() -> Result<PathBuf, Error> {
+    let top: PathBuf = revision(&["rev-parse", "--show-toplevel"])
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, "Not inside a git repository"))?;
+    if let Some(path) = revision(&["config", "--get", "core.hooksPath"]) {
+        return Ok(top.join(path));
+    }
+    revision(&["rev-parse", "--git-path", "hooks"])
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, "The git hooks directory is unknown"))
+}
+
+#[doc = "Check if the hook file was written by tux"]
+fn installed(path: &Path) -> bool {
+    read_to_string(path).is_ok_and(|content| content.contains(HOOK_MARKER))
+}
+
+///
+/// # Install
+///
+/// Write the hook, an existing hook not written by tux is kept and executed first.
+/// Returns the path of the hook.
+///
+/// # Errors
+///
+/// Outside of a git repository, on no write rights
+/// or when a hook not written by tux would replace a chained hook
+///
+pub fn install(hook: Hook) -> Result<PathBuf, Error> {
+    let directory: PathBuf = hooks_directory()?;
+    std::fs::create_dir_all(&directory)?;
+    let path: PathBuf = directory.join(hook.to_string());
+    if path.exists() && !installed(&path) {
+        let chained: PathBuf = hook.chained(&directory);
+        if chained.exists() {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!(
+                    "{} is not a tux hook and {} already exists, merge them by hand then install again",
+                    path.display(),
+                    chained.display()
+                ),
+            ));
+        }
+        rename(&path, chained)?;
+    }
+    write(&path, hook.script(&current_exe()?))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
+
+///
+/// # Uninstall
+///
+/// Remove the hook written by tux and put back the chained hook.
+/// Returns false when the hook was not written by tux.
+///
+/// # Errors
+///
+/// Outside of a git repository or on no write rights
+///
+pub fn uninstall(hook: Hook) -> Result<bool, Error> {
+    let directory: PathBuf = hooks_directory()?;
+    let path: PathBuf = directory.join(hook.to_string());
+    if !installed(&path) {
+        return Ok(false);
+    }
+    remove_file(&path)?;
+    let chained: PathBuf = hook.chained(&directory);
+    if chained.exists() {
+        rename(chained, &path)?;
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{git, repository, within};
+    use std::fs::create_dir_all;
+
+    #[test]
+    fn install_chains_the_existing_hook_and_uninstall_restores_it() {
+        let root: PathBuf = repository("hook-chain");
+        let hooks: PathBuf = root.join(".git/hooks");
+        create_dir_all(&hooks).expect("the hooks directory is created");
+        write(hooks.join("pre-commit"), "#!/bin/sh\nmake lint\n").expect("the hook is written");
+        within(&root, || {
+            let path: PathBuf = install(Hook::PreCommit).expect("the hook is installed");
+            assert!(installed(&path));
+            let chained: PathBuf = Hook::PreCommit.chained(&hooks);
+            assert_eq!(
+                read_to_string(&chained).expect("the hook is chained"),
+                "#!/bin/sh\nmake lint\n"
+            );
+            install(Hook::PreCommit).expect("the tux hook is replaced");
+            assert!(read_to_string(&chained).is_ok_and(|content| content.contains("make lint")));
+            assert!(uninstall(Hook::PreCommit).expect("the hook is removed"));
+            assert!(!chained.exists());
+            assert_eq!(
+                read_to_string(hooks.join("pre-commit")).expect("the hook is restored"),
+                "#!/bin/sh\nmake lint\n"
+            );
+            assert!(!uninstall(Hook::PreCommit).expect("a foreign hook is kept"));
+            assert!(hooks.join("pre-commit").exists());
+            assert!(!uninstall(Hook::PrePush).expect("a missing hook is ignored"));
+        });
+    }
+
+    #[test]
+    fn install_never_overwrites_a_chained_hook() {
+        let root: PathBuf = repository("hook-refuse");
+        let hooks: PathBuf = root.join(".git/hooks");
+        create_dir_all(&hooks).expect("the hooks directory is created");
+        write(hooks.join("pre-push"), "#!/bin/sh\nnew\n").expect("the hook is written");
+        write(Hook::PrePush.chained(&hooks), "#!/bin/sh\nold\n").expect("the hook is written");
+        within(&root, || {
+            let error: Error = install(Hook::PrePush).expect_err("the hooks conflict");
+            assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        });
+        assert_eq!(
+            read_to_string(hooks.join("pre-push")).expect("the hook is kept"),
+            "#!/bin/sh\nnew\n"
+        );
+        assert_eq!(
+            read_to_string(Hook::PrePush.chained(&hooks)).expect("the chained hook is kept"),
+            "#!/bin/sh\nold\n"
+        );
+    }
+
+    #[test]
+    fn install_follows_the_hooks_path() {
+        let root: PathBuf = repository("hook-path");
+        git(&root, &["config", "core.hooksPath", "tools/hooks"]);
+        let path: PathBuf = within(&root, || {
+            install(Hook::PrePush).expect("the hook is installed")
+        });
+        assert!(path.ends_with("tools/hooks/pre-push"));
+        assert!(root.join("tools/hooks/pre-push").exists());
+    }
+
+    #[test]
+    fn script_checks_the_staged_changes_before_a_commit() {
+        let tux: &Path = Path::new("/opt/my tools/tux");
+        let commit: String = Hook::PreCommit.script(tux);
+        assert!(commit.contains(HOOK_MARKER));
+        assert!(commit.contains("exec '/opt/my tools/tux' --profile pre-commit --staged\n"));
+        assert!(Hook::PrePush
+            .script(tux)
+            .contains("exec '/opt/my tools/tux' --profile pre-push\n"));
+        assert_eq!(Hook::from_name("pre-push"), Some(Hook::PrePush));
+        assert_eq!(Hook::from_name("post-merge"), None);
+    }
+}
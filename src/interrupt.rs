@@ -0,0 +1,54 @@
+use crate::output::show_cursor;
+use crate::staged::restore;
+use std::process::exit;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+#[doc = "The exit code of a run interrupted by a signal"]
+pub const INTERRUPTED: i32 = 130;
+
+#[doc = "Set by the first signal, the running tasks are stopped and no task is started"]
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Install the signal handler once"]
+static HANDLER: Once = Once::new();
+
+#[must_use]
+#[doc = "Check if the run has been cancelled"]
+pub fn cancelled() -> bool {
+    CANCELLED.load(Ordering::SeqCst)
+}
+
+#[doc = "Cancel the run, the running tasks are stopped by their executor"]
+pub fn cancel() {
+    CANCELLED.store(true, Ordering::SeqCst);
+}
+
+///
+/// # Handle signals
+///
+/// Cancel the run on Ctrl-C, on termination or when the terminal is closed.
+/// A second signal restores the unstaged changes and the cursor, then exits at once.
+///
+pub fn handle_signals() {
+    #[cfg(unix)]
+    HANDLER.call_once(|| {
+        use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+        use signal_hook::iterator::Signals;
+        if let Ok(mut signals) = Signals::new([SIGINT, SIGTERM, SIGHUP]) {
+            std::thread::spawn(move || {
+                let mut received = signals.forever();
+                if received.next().is_some() {
+                    cancel();
+                }
+                if received.next().is_some() {
+                    if let Err(error) = restore() {
+                        eprintln!("{error}");
+                    }
+                    show_cursor();
+                    exit(INTERRUPTED);
+                }
+            });
+        }
+    });
+}
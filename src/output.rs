@@ -1,94 +1,260 @@
+use crate::interrupt::cancelled;
 use crossterm::terminal::{Clear, ClearType};
 use crossterm::{
-    cursor::{Hide, MoveTo},
+    cursor::{Hide, MoveTo, Show},
     execute,
-    style::{Color, Print, SetForegroundColor},
+    style::{force_color_output, Color, Print, SetForegroundColor, Stylize},
     terminal::size,
 };
 use std::{
-    io::{stdout, Error},
-    process::{Command, ExitCode},
+    env::var_os,
+    fs::File,
+    io::{stderr, stdout, BufRead, BufReader, Error, IsTerminal, Read, Write},
+    process::{Child, Command, ExitCode, ExitStatus, Output, Stdio},
+    str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex, MutexGuard, PoisonError,
     },
-    thread::{self, sleep},
-    time::Duration,
+    thread::{self, sleep, JoinHandle},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 #[doc = "The waiting task spinner strings"]
 pub const SPINNERS: [&str; 4] = [". ", "..", ".:", "::"];
 
-#[doc = "return the status code of the code checkup"]
-#[must_use]
-pub const fn zuu_exit(status: &Result<(), Error>) -> ExitCode {
-    if status.is_err() {
-        return ExitCode::FAILURE;
+#[doc = "The time given to a stopped task to exit after SIGTERM, before SIGKILL"]
+pub const GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+#[doc = "The time given to a tool to print its version"]
+pub const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[doc = "The time between two checks of a running task"]
+pub const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[doc = "Serialize the terminal writes of the tasks running in parallel"]
+static TERMINAL: Mutex<()> = Mutex::new(());
+
+#[doc = "Print line-oriented messages instead of drawing on the screen"]
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Print the colors"]
+static COLORED: AtomicBool = AtomicBool::new(true);
+
+#[doc = "Copy the output of the tasks to the terminal while they run"]
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+#[doc = "Take the terminal until the guard is dropped"]
+fn terminal() -> MutexGuard<'static, ()> {
+    TERMINAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc = "When the output is colored"]
+pub enum ColorMode {
+    #[doc = "Color a terminal unless `NO_COLOR` is set"]
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!(
+                "{s} is not a color mode, use never, always or auto"
+            )),
+        }
     }
-    ExitCode::SUCCESS
 }
 
 ///
-/// # Ok
+/// # Configure
 ///
-/// Print a success message
+/// Choose between the terminal and the plain output from stdout,
+/// a pipe or a file get line-oriented messages with timestamps.
+/// The verbose mode is always line-oriented.
 ///
-/// # Panics
+pub fn configure(color: ColorMode, verbose: bool) {
+    let tty: bool = stdout().is_terminal();
+    let colored: bool = match color {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => tty && var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+    };
+    PLAIN.store(
+        verbose || !tty || size().is_ok_and(|(cols, _)| cols.eq(&0)),
+        Ordering::SeqCst,
+    );
+    VERBOSE.store(verbose, Ordering::SeqCst);
+    COLORED.store(colored, Ordering::SeqCst);
+    force_color_output(colored);
+}
+
 ///
-/// On fail to print the description
+/// # Fit
 ///
-/// # Errors
+/// Print line-oriented messages when the tasks, a row by task, do not fit on the screen.
+/// The rows past the bottom of the terminal would overwrite each other.
 ///
+pub fn fit(rows: usize) {
+    if !plain() && size().is_ok_and(|(_, height)| rows >= usize::from(height)) {
+        show_cursor();
+        PLAIN.store(true, Ordering::SeqCst);
+    }
+}
+
+#[must_use]
+#[doc = "Check if the messages are printed line by line"]
+pub fn plain() -> bool {
+    PLAIN.load(Ordering::SeqCst) || size().is_err()
+}
+
+#[must_use]
+#[doc = "Check if the output of the tasks is copied to the terminal"]
+pub fn verbose() -> bool {
+    VERBOSE.load(Ordering::SeqCst)
+}
+
+#[must_use]
+#[doc = "Check if the output is colored"]
+pub fn colored() -> bool {
+    COLORED.load(Ordering::SeqCst)
+}
+
+#[doc = "Clear the screen, nothing is printed in plain mode"]
+pub fn clear() {
+    if !plain() {
+        assert!(execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0)).is_ok());
+    }
+}
+
+#[doc = "Hide the cursor during the checkup, nothing is printed in plain mode"]
+pub fn hide_cursor() {
+    if !plain() {
+        assert!(execute!(stdout(), Hide).is_ok());
+    }
+}
+
+#[doc = "Show the cursor again, nothing is printed in plain mode"]
+pub fn show_cursor() {
+    if !plain() {
+        assert!(execute!(stdout(), Show).is_ok());
+    }
+}
+
 ///
+/// # Timestamp
 ///
-pub fn ok(description: &str, x: usize) {
+/// The current UTC time formatted as RFC 3339, `2024-01-31T12:00:00Z`
+///
+#[must_use]
+pub fn timestamp() -> String {
+    let seconds: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let days: i64 = i64::try_from(seconds / 86_400).unwrap_or_default();
+    let time: u64 = seconds % 86_400;
+    // civil date from the days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z: i64 = days + 719_468;
+    let era: i64 = z.div_euclid(146_097);
+    let doe: i64 = z.rem_euclid(146_097);
+    let yoe: i64 = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: i64 = (5 * doy + 2) / 153;
+    let day: i64 = doy - (153 * mp + 2) / 5 + 1;
+    let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year: i64 = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[doc = "return the status code of the code checkup"]
+#[must_use]
+pub const fn zuu_exit(status: &Result<(), Error>) -> ExitCode {
+    if status.is_err() {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[doc = "Print a timestamped message on its own line"]
+fn line(description: &str, status: &str, color: Color) {
+    let status: String = if colored() {
+        format!("{}{}{}", "[".blue(), status.with(color), "]".blue())
+    } else {
+        format!("[{status}]")
+    };
+    let _terminal = terminal();
+    assert!(
+        execute!(
+            stdout(),
+            Print(format!("{} {status} {description}\n", timestamp()))
+        )
+        .is_ok(),
+        "Failed to print the message"
+    );
+}
+
+#[doc = "Print a message on the task line with the status on the right"]
+fn mark(description: &str, x: usize, status: &str, color: Color) {
+    if plain() {
+        return line(description, status, color);
+    }
+    let _terminal = terminal();
     if let Ok((cols, _rows)) = size() {
         if let Ok(y) = u16::try_from(x) {
-            let status: &str = "[ ok ]";
-            if let Ok(len) = u16::try_from(status.len()) {
+            if let Ok(len) = u16::try_from(status.len() + 2) {
                 let status_position: u16 = cols.saturating_sub(len);
                 assert!(
                     execute!(
                         stdout(),
-                        SetForegroundColor(Color::Green),
+                        SetForegroundColor(color),
                         MoveTo(0, y),
                         Print("*"),
                         MoveTo(2, y),
                         SetForegroundColor(Color::White),
                         Print(description),
-                        MoveTo(status_position, 1),
                         SetForegroundColor(Color::Blue),
                         MoveTo(status_position, y),
                         Print("["),
-                        SetForegroundColor(Color::Green),
-                        Print(" ok "),
+                        SetForegroundColor(color),
+                        Print(status),
                         SetForegroundColor(Color::Blue),
                         Print("]"),
                         SetForegroundColor(Color::Reset),
                     )
                     .is_ok(),
-                    "Failed to print success message"
+                    "Failed to print the message"
                 );
             }
-        } else {
-            assert!(
-                execute!(
-                    stdout(),
-                    SetForegroundColor(Color::Green),
-                    MoveTo(0, 1),
-                    Print("*"),
-                    MoveTo(2, 1),
-                    SetForegroundColor(Color::White),
-                    Print(description),
-                    SetForegroundColor(Color::Reset),
-                )
-                .is_ok(),
-                "Failed to print success message"
-            );
         }
     }
 }
 
+///
+/// # Ok
+///
+/// Print a success message
+///
+/// # Panics
+///
+/// On fail to print the description
+///
+pub fn ok(description: &str, x: usize) {
+    mark(description, x, " ok ", Color::Green);
+}
+
 ///
 /// # Ko
 ///
@@ -99,46 +265,124 @@ pub fn ok(description: &str, x: usize) {
 /// On fail to print the description
 ///
 pub fn ko(description: &str, x: usize) {
-    if let Ok((cols, _row)) = size() {
-        if let Ok(y) = u16::try_from(x) {
-            let status: &str = "[ !! ]";
-            if let Ok(len) = u16::try_from(status.len()) {
-                let status_position: u16 = cols.saturating_sub(len);
-                assert!(
-                    execute!(
-                        stdout(),
-                        SetForegroundColor(Color::Red),
-                        MoveTo(0, y),
-                        Print("*"),
-                        MoveTo(2, y),
-                        SetForegroundColor(Color::White),
-                        Print(description),
-                        SetForegroundColor(Color::Blue),
-                        MoveTo(status_position, y),
-                        Print("["),
-                        SetForegroundColor(Color::Red),
-                        Print(" !! "),
-                        SetForegroundColor(Color::Blue),
-                        Print("]"),
-                        SetForegroundColor(Color::Reset),
-                    )
-                    .is_ok(),
-                    "Failed to print error message"
-                );
-            }
-        }
+    mark(description, x, " !! ", Color::Red);
+}
+
+///
+/// # Skip
+///
+/// Print a skipped task message
+///
+/// # Panics
+///
+/// On fail to print the description
+///
+pub fn skip(description: &str, x: usize) {
+    mark(description, x, " -- ", Color::Yellow);
+}
+
+///
+/// # Excerpt
+///
+/// Print the last lines of a failed task output under a title
+///
+/// # Panics
+///
+/// On fail to print the excerpt
+///
+pub fn excerpt(title: &str, lines: &str) {
+    let title: String = if colored() {
+        title.red().bold().to_string()
     } else {
-        assert!(
-            execute!(stdout(), Print(description)).is_ok(),
-            "Failed to print error message"
-        );
+        title.to_string()
+    };
+    let mut text: String = format!("\n{title}\n");
+    for line in lines.lines() {
+        text.push_str(format!("    {line}\n").as_str());
     }
+    assert!(
+        execute!(stdout(), Print(text)).is_ok(),
+        "Failed to print the excerpt"
+    );
 }
+
+#[doc = "Write the output of a task to its files and to stderr, line by line, while it runs"]
+pub struct Tee {
+    #[doc = "Printed before every line"]
+    prefix: String,
+    stdout: File,
+    stderr: File,
+    #[doc = "The text printed for a line of the standard output, none to print nothing"]
+    render: fn(&str) -> Option<String>,
+}
+
+impl Tee {
+    #[must_use]
+    #[doc = "Copy the piped outputs of the command to the files"]
+    pub fn new(prefix: String, stdout: File, stderr: File) -> Self {
+        Self {
+            prefix,
+            stdout,
+            stderr,
+            render: |line| Some(line.to_string()),
+        }
+    }
+
+    #[must_use]
+    #[doc = "Print the lines of the standard output as rendered, the file gets them unchanged"]
+    pub fn with_render(mut self, render: fn(&str) -> Option<String>) -> Self {
+        self.render = render;
+        self
+    }
+
+    #[doc = "Start copying the outputs of the child, the copies end with the child outputs"]
+    fn start(self, child: &mut Child) -> Vec<JoinHandle<()>> {
+        let mut copies: Vec<JoinHandle<()>> = Vec::new();
+        if let Some(output) = child.stdout.take() {
+            copies.push(copy(output, self.stdout, self.prefix.clone(), self.render));
+        }
+        if let Some(output) = child.stderr.take() {
+            copies.push(copy(output, self.stderr, self.prefix, |line| {
+                Some(line.to_string())
+            }));
+        }
+        copies
+    }
+}
+
+#[doc = "Copy an output to the file and its rendered lines to stderr until its end"]
+fn copy(
+    output: impl Read + Send + 'static,
+    mut file: File,
+    prefix: String,
+    render: fn(&str) -> Option<String>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(output);
+        let mut line: Vec<u8> = Vec::new();
+        while reader
+            .read_until(b'\n', &mut line)
+            .is_ok_and(|read| read > 0)
+        {
+            let _ = file.write_all(&line);
+            let text = String::from_utf8_lossy(&line);
+            if let Some(text) = render(text.trim_end_matches(['\n', '\r'])) {
+                let _terminal = terminal();
+                for printed in text.lines() {
+                    let _ = writeln!(stderr(), "{prefix} | {printed}");
+                }
+            }
+            line.clear();
+        }
+    })
+}
+
 ///
+/// # Exec
 ///
-/// # Exec
-///
-/// Execute the command writted in toml
+/// Execute the command writted in toml, a spinner is drawn on the terminal
+/// and a start line is printed in plain mode.
+/// None is returned when the command has been stopped by the timeout or by the cancellation.
 ///
 /// # Panics
 ///
@@ -146,7 +390,7 @@ pub fn ko(description: &str, x: usize) {
 ///
 /// # Errors
 ///
-/// On check failure
+/// On spawn failure
 ///
 pub fn waiting(
     data: (
@@ -156,80 +400,249 @@ pub fn waiting(
     ),
     cmd: &mut Command,
     x: usize,
-) -> std::io::Result<()> {
+    timeout: Option<Duration>,
+    tee: Option<Tee>,
+) -> std::io::Result<Option<ExitStatus>> {
+    let (Ok((cols, _row)), Ok(y), false) = (size(), u16::try_from(x), plain()) else {
+        line(data.0.as_str(), " .. ", Color::Blue);
+        let command_output: Option<ExitStatus> = wait_for(cmd, timeout, tee)?;
+        finished(&data, x, command_output, timeout);
+        return Ok(command_output);
+    };
     let spinner_done = Arc::new(AtomicBool::new(false));
     let spinner_done_clone = Arc::clone(&spinner_done);
+    let guard = terminal();
+    assert!(execute!(
+        stdout(),
+        MoveTo(0, y),
+        SetForegroundColor(Color::Green),
+        Print("*"),
+        MoveTo(2, y),
+        SetForegroundColor(Color::White),
+        Print(data.0.to_string()),
+        SetForegroundColor(Color::Reset),
+    )
+    .is_ok());
+    drop(guard);
 
-    if let Ok((cols, _row)) = size() {
-        let status: &str = "   ";
-        if let Ok(len) = u16::try_from(status.len()) {
-            if let Ok(y) = u16::try_from(x) {
-                let status_position: u16 = cols.saturating_sub(len);
+    let title: String = data.0.to_string();
+    let spinner_thread = thread::spawn(move || {
+        let mut output = stdout();
+        let status_position: u16 = cols.saturating_sub(6);
+        while !spinner_done_clone.load(Ordering::SeqCst) {
+            for spin in SPINNERS {
+                if spinner_done_clone.load(Ordering::SeqCst) {
+                    break;
+                }
+                let guard = terminal();
                 assert!(execute!(
-                    stdout(),
-                    MoveTo(0, y),
+                    output,
+                    Hide,
                     SetForegroundColor(Color::Green),
+                    MoveTo(0, y),
                     Print("*"),
                     MoveTo(2, y),
                     SetForegroundColor(Color::White),
-                    Print(data.0.to_string()),
+                    Print(title.as_str()),
                     MoveTo(status_position, y),
-                    SetForegroundColor(Color::White),
-                    Print(" "),
+                    SetForegroundColor(Color::Blue),
+                    Print("["),
+                    SetForegroundColor(Color::Green),
+                    Print(format!(" {spin} ")),
+                    SetForegroundColor(Color::Blue),
+                    Print("]"),
                     SetForegroundColor(Color::Reset),
                 )
                 .is_ok());
+                drop(guard);
+                sleep(Duration::from_millis(100));
+            }
+        }
+    });
 
-                let spinner_thread = thread::spawn(move || {
-                    let mut output = stdout();
-                    while !spinner_done_clone.load(Ordering::SeqCst) {
-                        let status: &str = "[ :: ]";
-                        if let Ok(len) = u16::try_from(status.len()) {
-                            let status_position: u16 = cols.saturating_sub(len);
-                            for spin in SPINNERS {
-                                assert!(execute!(
-                                    output,
-                                    Hide,
-                                    SetForegroundColor(Color::Green),
-                                    MoveTo(0, y),
-                                    Print("*"),
-                                    MoveTo(2, y),
-                                    SetForegroundColor(Color::White),
-                                    Print(data.0.to_string()),
-                                    MoveTo(status_position, y),
-                                    SetForegroundColor(Color::Blue),
-                                    Print("["),
-                                    SetForegroundColor(Color::Green),
-                                    Print(format!(" {spin} ")),
-                                    SetForegroundColor(Color::Blue),
-                                    Print("]"),
-                                    SetForegroundColor(Color::Reset),
-                                )
-                                .is_ok());
-                                sleep(Duration::from_millis(500));
-                            }
-                        }
-                    }
-                });
-
-                let command_output = cmd.spawn()?.wait()?.success();
-
-                spinner_done.store(true, Ordering::SeqCst);
-                spinner_thread.join().unwrap();
-                assert!(
-                    crossterm::execute!(stdout(), MoveTo(0, y), Clear(ClearType::CurrentLine))
-                        .is_ok()
-                );
+    let command_output: std::io::Result<Option<ExitStatus>> = wait_for(cmd, timeout, tee);
+
+    spinner_done.store(true, Ordering::SeqCst);
+    spinner_thread.join().unwrap();
+    let guard = terminal();
+    assert!(crossterm::execute!(stdout(), MoveTo(0, y), Clear(ClearType::CurrentLine)).is_ok());
+    drop(guard);
+
+    let command_output: Option<ExitStatus> = command_output?;
+    finished(&data, x, command_output, timeout);
+    Ok(command_output)
+}
+
+#[doc = "Print the end of a task, the timeout is given when it has been stopped"]
+fn finished(
+    data: &(String, String, String),
+    x: usize,
+    status: Option<ExitStatus>,
+    timeout: Option<Duration>,
+) {
+    match status {
+        Some(status) if status.success() => ok(data.1.as_str(), x),
+        Some(_) => ko(data.2.as_str(), x),
+        None if cancelled() => mark(
+            format!("{} cancelled", data.0).as_str(),
+            x,
+            " -- ",
+            Color::Yellow,
+        ),
+        None => mark(
+            format!(
+                "{} timed out after {}s",
+                data.0,
+                timeout.unwrap_or_default().as_secs()
+            )
+            .as_str(),
+            x,
+            "time",
+            Color::Red,
+        ),
+    }
+}
+
+///
+/// # Wait for
+///
+/// Run the command until it exits, until the timeout is reached or until the run is cancelled,
+/// the command is then terminated and None is returned.
+/// The outputs are copied by the tee when given, the command outputs must then be piped.
+///
+/// # Errors
+///
+/// On spawn failure
+///
+pub fn wait_for(
+    cmd: &mut Command,
+    timeout: Option<Duration>,
+    tee: Option<Tee>,
+) -> std::io::Result<Option<ExitStatus>> {
+    let mut child: Child = cmd.spawn()?;
+    let copies: Vec<JoinHandle<()>> = tee.map(|tee| tee.start(&mut child)).unwrap_or_default();
+    let start = Instant::now();
+    let status: Option<ExitStatus> = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if cancelled() || timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+            terminate(&mut child, GRACE_PERIOD)?;
+            break None;
+        }
+        sleep(POLL_INTERVAL);
+    };
+    for copy in copies {
+        let _ = copy.join();
+    }
+    Ok(status)
+}
 
-                return if command_output {
-                    ok(data.1.as_str(), x);
-                    Ok(())
-                } else {
-                    ko(data.2.as_str(), x);
-                    Err(Error::new(std::io::ErrorKind::Other, "Command failed"))
-                };
+///
+/// # Terminate
+///
+/// Send SIGTERM to the process group of the child, then SIGKILL to what is left
+/// after the grace period. The child alone is killed outside of unix.
+///
+/// # Errors
+///
+/// When the child can not be waited
+///
+pub fn terminate(child: &mut Child, grace: Duration) -> std::io::Result<ExitStatus> {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{killpg, Signal};
+        use nix::unistd::Pid;
+        let group: Pid = Pid::from_raw(i32::try_from(child.id()).unwrap_or_default());
+        if killpg(group, Signal::SIGTERM).is_ok() {
+            let start = Instant::now();
+            while start.elapsed() < grace && child.try_wait()?.is_none() {
+                sleep(Duration::from_millis(100));
             }
+            let _ = killpg(group, Signal::SIGKILL);
         }
     }
-    Err(Error::new(std::io::ErrorKind::Other, "Error encountered"))
+    let _ = child.kill();
+    child.wait()
+}
+
+#[doc = "Read a piped output of a child until it is closed"]
+fn drain(output: Option<impl Read + Send + 'static>) -> Option<JoinHandle<Vec<u8>>> {
+    output.map(|mut output| {
+        thread::spawn(move || {
+            let mut bytes: Vec<u8> = Vec::new();
+            let _ = output.read_to_end(&mut bytes);
+            bytes
+        })
+    })
+}
+
+///
+/// # Bounded output
+///
+/// The outputs of the command when it exits before the timeout,
+/// the command is terminated with its process group otherwise and None is returned
+///
+/// # Errors
+///
+/// On spawn failure
+///
+pub fn bounded_output(cmd: &mut Command, timeout: Duration) -> std::io::Result<Option<Output>> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    let mut child: Child = cmd.spawn()?;
+    let stdout: Option<JoinHandle<Vec<u8>>> = drain(child.stdout.take());
+    let stderr: Option<JoinHandle<Vec<u8>>> = drain(child.stderr.take());
+    let start = Instant::now();
+    let status: Option<ExitStatus> = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if cancelled() || start.elapsed() >= timeout {
+            terminate(&mut child, GRACE_PERIOD)?;
+            break None;
+        }
+        sleep(POLL_INTERVAL);
+    };
+    let joined = |handle: Option<JoinHandle<Vec<u8>>>| {
+        handle
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default()
+    };
+    let (stdout, stderr): (Vec<u8>, Vec<u8>) = (joined(stdout), joined(stderr));
+    Ok(status.map(|status| Output {
+        status,
+        stdout,
+        stderr,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_output_stops_the_commands_not_exiting_in_time() {
+        let mut quick: Command = Command::new("echo");
+        quick.arg("1.0.0");
+        let output: Option<Output> =
+            bounded_output(&mut quick, Duration::from_secs(5)).expect("echo is executed");
+        assert_eq!(
+            output.map(|output| output.stdout),
+            Some(b"1.0.0\n".to_vec())
+        );
+        let mut slow: Command = Command::new("sleep");
+        slow.arg("30");
+        let start = Instant::now();
+        assert!(bounded_output(&mut slow, Duration::from_millis(100))
+            .expect("sleep is executed")
+            .is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
 }
@@ -0,0 +1,408 @@
+use crate::ask::{Report, FAILURE, SUCCESS};
+use crate::sarif::{readable, Parser};
+use crate::task::{seconds, TaskResult, TaskStatus};
+use cli_table::{format::Justify, Color, Table};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::fmt::Write as _;
+use std::fs::{read_to_string, File};
+use std::io::{Error, Write};
+use std::path::Path;
+use std::time::Duration;
+
+#[doc = "The machine-readable report stored after every run"]
+pub const JSON_REPORT: &str = "zuu/report.json";
+
+#[doc = "The junit report stored after every run"]
+pub const JUNIT_REPORT: &str = "zuu/junit.xml";
+
+#[doc = "Number of stderr lines given with a failed task"]
+pub const FAILURE_LINES: usize = 50;
+
+#[doc = "Number of tasks listed in the slowest tasks summary"]
+pub const SLOWEST_TASKS: usize = 5;
+
+#[derive(Serialize)]
+#[doc = "The content of the json report"]
+pub struct Summary<'a> {
+    #[doc = "0 when every language has passed, 1 otherwise"]
+    pub code: i32,
+    #[serde(serialize_with = "seconds")]
+    #[doc = "The sum of the task durations, in seconds"]
+    pub duration: Duration,
+    #[doc = "The tasks which have taken the most time"]
+    pub slowest: Vec<Timing>,
+    pub languages: &'a [Report],
+}
+
+impl<'a> Summary<'a> {
+    #[must_use]
+    pub fn new(reports: &'a [Report]) -> Self {
+        Self {
+            code: if reports.iter().any(|report| report.code.eq(&FAILURE)) {
+                FAILURE
+            } else {
+                SUCCESS
+            },
+            duration: reports.iter().map(|report| report.duration).sum(),
+            slowest: slowest(reports, SLOWEST_TASKS),
+            languages: reports,
+        }
+    }
+}
+
+#[derive(Table, Serialize)]
+#[doc = "The time taken by a task"]
+pub struct Timing {
+    #[table(title = "TASK", justify = "Justify::Left", color = "Color::White")]
+    pub task: String,
+    #[table(title = "STATUS", justify = "Justify::Left", color = "Color::White")]
+    pub status: TaskStatus,
+    #[table(
+        title = "DURATION",
+        justify = "Justify::Right",
+        color = "Color::White",
+        display_fn = "elapsed"
+    )]
+    #[serde(serialize_with = "seconds")]
+    pub duration: Duration,
+}
+
+///
+/// # Slowest
+///
+/// The executed tasks which have taken the most time, the slowest first.
+/// The results replayed from the cache are not listed.
+///
+#[must_use]
+pub fn slowest(reports: &[Report], limit: usize) -> Vec<Timing> {
+    let mut timings: Vec<Timing> = reports
+        .iter()
+        .flat_map(|report| &report.tasks)
+        .filter(|result| result.status.is_executed() && !result.cached)
+        .map(|result| Timing {
+            task: result.id(),
+            status: result.status,
+            duration: result.duration,
+        })
+        .collect();
+    timings.sort_by_key(|timing| Reverse(timing.duration));
+    timings.truncate(limit);
+    timings
+}
+
+#[must_use]
+#[doc = "A duration as displayed, `4.2s` or `3m07s`"]
+pub fn elapsed(duration: &Duration) -> String {
+    let seconds: u64 = duration.as_secs();
+    if seconds < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    }
+}
+
+///
+/// # Json
+///
+/// Serialize the reports with every task result
+///
+/// # Errors
+///
+/// On serialization failure
+///
+pub fn json(reports: &[Report]) -> Result<String, Error> {
+    serde_json::to_string_pretty(&Summary::new(reports)).map_err(Error::other)
+}
+
+///
+/// # Save
+///
+/// Write a report file
+///
+/// # Errors
+///
+/// On no write rights
+///
+pub fn save(path: &str, content: &str) -> Result<(), Error> {
+    let mut file: File = File::create(path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()
+}
+
+///
+/// # Junit
+///
+/// Serialize the reports as junit xml, a test suite by language and a test case by task
+///
+#[must_use]
+pub fn junit(reports: &[Report]) -> String {
+    let mut xml: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let count = |status: fn(TaskStatus) -> bool| -> usize {
+        reports
+            .iter()
+            .flat_map(|report| &report.tasks)
+            .filter(|result| status(result.status))
+            .count()
+    };
+    let total: usize = reports.iter().map(|report| report.tasks.len()).sum();
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="tux" tests="{total}" failures="{}" skipped="{}" time="{:.3}">"#,
+        count(|status| status.is_failure()),
+        count(|status| status.is_skipped()),
+        time(reports.iter().flat_map(|report| &report.tasks)),
+    );
+    for report in reports {
+        let failures: usize = report
+            .tasks
+            .iter()
+            .filter(|result| result.status.is_failure())
+            .count();
+        let skipped: usize = report
+            .tasks
+            .iter()
+            .filter(|result| result.status.is_skipped())
+            .count();
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{failures}" skipped="{skipped}" time="{:.3}">"#,
+            escape(&report.name()),
+            report.tasks.len(),
+            time(report.tasks.iter()),
+        );
+        for result in &report.tasks {
+            let _ = write!(
+                xml,
+                r#"    <testcase name="{}" classname="{}.{}" time="{:.3}">"#,
+                escape(&result.task.title),
+                escape(&report.name()),
+                escape(&result.task.name),
+                result.duration.as_secs_f64(),
+            );
+            let properties: String = properties(result);
+            if !properties.is_empty() {
+                let _ = write!(xml, "\n      <properties>{properties}</properties>");
+            }
+            match result.status {
+                TaskStatus::Pass | TaskStatus::Flaky if !properties.is_empty() => {
+                    let _ = write!(xml, "\n    ");
+                }
+                TaskStatus::Pass | TaskStatus::Flaky => {}
+                TaskStatus::Fail => {
+                    let _ = write!(
+                        xml,
+                        "\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    ",
+                        escape(&result.task.failure),
+                        result.task.category,
+                        escape(&failure_output(result, FAILURE_LINES).1),
+                    );
+                }
+                TaskStatus::Timeout => {
+                    let _ = write!(
+                        xml,
+                        "\n      <failure message=\"{}\" type=\"timeout\">{}</failure>\n    ",
+                        escape(&format!(
+                            "Stopped after {} seconds",
+                            result.task.timeout.unwrap_or_default()
+                        )),
+                        escape(&failure_output(result, FAILURE_LINES).1),
+                    );
+                }
+                TaskStatus::Missing => {
+                    let _ = write!(
+                        xml,
+                        "\n      <failure message=\"{}\" type=\"missing\"/>\n    ",
+                        escape(&tail(&result.stderr, 1)),
+                    );
+                }
+                TaskStatus::Skipped => {
+                    let _ = write!(xml, "\n      <skipped/>\n    ");
+                }
+                TaskStatus::Cancelled => {
+                    let _ = write!(xml, "\n      <skipped message=\"cancelled\"/>\n    ");
+                }
+                TaskStatus::Unaffected => {
+                    let _ = write!(
+                        xml,
+                        "\n      <skipped message=\"unaffected by the changes\"/>\n    "
+                    );
+                }
+            }
+            let _ = writeln!(xml, "</testcase>");
+        }
+        let _ = writeln!(xml, "  </testsuite>");
+    }
+    let _ = writeln!(xml, "</testsuites>");
+    xml
+}
+
+#[doc = "The junit properties of a task replayed from the cache or executed more than once"]
+fn properties(result: &TaskResult) -> String {
+    let mut properties: String = String::new();
+    if result.cached {
+        properties.push_str(r#"<property name="cached" value="true"/>"#);
+    }
+    if result.attempts > 1 {
+        let _ = write!(
+            properties,
+            r#"<property name="attempts" value="{}"/>"#,
+            result.attempts
+        );
+    }
+    if result.status.eq(&TaskStatus::Flaky) {
+        properties.push_str(r#"<property name="flaky" value="true"/>"#);
+    }
+    properties
+}
+
+#[doc = "The sum of the durations in seconds"]
+fn time<'a>(results: impl Iterator<Item = &'a TaskResult>) -> f64 {
+    results
+        .map(|result| result.duration)
+        .sum::<Duration>()
+        .as_secs_f64()
+}
+
+#[doc = "Escape a text for a xml attribute or element, the invalid characters are removed"]
+#[must_use]
+pub fn escape(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[doc = "The last lines of a captured output"]
+#[must_use]
+pub fn tail(path: &Path, lines: usize) -> String {
+    last(&read_to_string(path).unwrap_or_default(), lines)
+}
+
+///
+/// # Failure output
+///
+/// The file and the last lines explaining a failure: the rendered clippy diagnostics,
+/// the error output, or the standard output when the error output is empty
+///
+#[must_use]
+pub fn failure_output(result: &TaskResult, lines: usize) -> (&Path, String) {
+    if Parser::detect(&result.task).eq(&Some(Parser::Clippy)) {
+        let diagnostics: String = readable(&read_to_string(&result.stdout).unwrap_or_default());
+        if !diagnostics.trim().is_empty() {
+            return (&result.stdout, last(&diagnostics, lines));
+        }
+    }
+    match tail(&result.stderr, lines) {
+        errors if errors.trim().is_empty() => (&result.stdout, tail(&result.stdout, lines)),
+        errors => (&result.stderr, errors),
+    }
+}
+
+#[doc = "The last lines of a text"]
+#[must_use]
+pub fn last(text: &str, lines: usize) -> String {
+    let all: Vec<&str> = text.lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::Job;
+    use crate::support::{Language, ROOT_PROJECT};
+    use crate::task::{Task, TaskCategory};
+    use crate::testing::scratch;
+    use std::fs::{remove_dir_all, write};
+    use std::path::PathBuf;
+
+    fn result(directory: &Path, task: Task, status: TaskStatus, outputs: [&str; 2]) -> TaskResult {
+        let stdout: PathBuf = directory.join(format!("{}.stdout.txt", task.name));
+        let stderr: PathBuf = directory.join(format!("{}.stderr.txt", task.name));
+        write(&stdout, outputs[0]).expect("the output is written");
+        write(&stderr, outputs[1]).expect("the output is written");
+        let job: Job = Job {
+            project: ROOT_PROJECT.to_string(),
+            language: Language::Rust,
+            task,
+        };
+        TaskResult {
+            stdout,
+            stderr,
+            ..job.result(status)
+        }
+    }
+
+    #[test]
+    fn escape_replaces_the_xml_characters_and_drops_the_controls() {
+        assert_eq!(
+            escape("<a href=\"x\">'&'</a>\u{1b}[31m\n"),
+            "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;[31m\n"
+        );
+    }
+
+    #[test]
+    fn junit_counts_the_failures_and_the_skipped_tasks() {
+        let directory: PathBuf = scratch("junit-counts");
+        let task = |name: &str| Task::new(name, TaskCategory::Custom, name, "true", "", "");
+        let results: Vec<TaskResult> = vec![
+            result(&directory, task("pass"), TaskStatus::Pass, ["", ""]),
+            result(&directory, task("fail"), TaskStatus::Fail, ["", "broken"]),
+            result(&directory, task("skip"), TaskStatus::Skipped, ["", ""]),
+            result(
+                &directory,
+                task("tool"),
+                TaskStatus::Missing,
+                ["", "x is not installed"],
+            ),
+        ];
+        let xml: String = junit(&Report::summarize(&results));
+        assert!(xml.contains(r#"<testsuites name="tux" tests="4" failures="2" skipped="1""#));
+        assert!(xml.contains(">broken</failure>"));
+        assert!(xml.contains(r#"<failure message="x is not installed" type="missing"/>"#));
+        remove_dir_all(&directory).expect("the directory is removed");
+    }
+
+    #[test]
+    fn junit_explains_a_failure_without_error_output() {
+        let directory: PathBuf = scratch("junit-stdout");
+        let clippy: Task = Task::new(
+            "lint",
+            TaskCategory::Lint,
+            "Linting",
+            "cargo clippy --message-format=json",
+            "",
+            "Lint failed",
+        );
+        let test: Task = Task::new("test", TaskCategory::Test, "Testing", "pytest", "", "");
+        let results: Vec<TaskResult> = vec![
+            result(
+                &directory,
+                clippy,
+                TaskStatus::Fail,
+                [include_str!("../tests/fixtures/clippy.json"), ""],
+            ),
+            result(
+                &directory,
+                test,
+                TaskStatus::Fail,
+                ["FAILED test_app.py::test_run", ""],
+            ),
+        ];
+        let xml: String = junit(&Report::summarize(&results));
+        assert!(xml.contains("missing `package.description` metadata"));
+        assert!(!xml.contains("compiler-message"));
+        assert!(xml.contains(">FAILED test_app.py::test_run</failure>"));
+        remove_dir_all(&directory).expect("the directory is removed");
+    }
+}
@@ -0,0 +1,746 @@
+use crate::ask::Report;
+use crate::task::{Task, TaskResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::read_to_string;
+use std::io::Error;
+use std::path::{Path, PathBuf};
+
+#[doc = "The sarif report stored after every run"]
+pub const SARIF_REPORT: &str = "zuu/report.sarif";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[doc = "The linters and the auditors with a known output format"]
+pub enum Parser {
+    Clippy,
+    Flake8,
+    GolangciLint,
+    Eslint,
+    CargoAudit,
+    NpmAudit,
+    Govulncheck,
+}
+
+impl Parser {
+    ///
+    /// # Detect
+    ///
+    /// Find the parser of a task from its command
+    ///
+    #[must_use]
+    pub fn detect(task: &Task) -> Option<Self> {
+        let command: String = task.command_line();
+        if task.program.eq("cargo") && task.args.iter().any(|arg| arg.eq("clippy")) {
+            Some(Self::Clippy)
+        } else if task.program.eq("cargo") && task.args.first().is_some_and(|arg| arg.eq("audit")) {
+            Some(Self::CargoAudit)
+        } else if task.program.eq("npm") && task.args.first().is_some_and(|arg| arg.eq("audit")) {
+            Some(Self::NpmAudit)
+        } else if command.contains("govulncheck") {
+            Some(Self::Govulncheck)
+        } else if command.contains("flake8") {
+            Some(Self::Flake8)
+        } else if command.contains("golangci-lint") {
+            Some(Self::GolangciLint)
+        } else if command.contains("eslint")
+            || (task.program.eq("npm")
+                && task.args.join(" ").eq("run lint")
+                && lint_script(task).contains("eslint"))
+        {
+            Some(Self::Eslint)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    #[doc = "The tool name written in the sarif report"]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Clippy => "clippy",
+            Self::Flake8 => "flake8",
+            Self::GolangciLint => "golangci-lint",
+            Self::Eslint => "eslint",
+            Self::CargoAudit => "cargo-audit",
+            Self::NpmAudit => "npm-audit",
+            Self::Govulncheck => "govulncheck",
+        }
+    }
+
+    #[doc = "Read the findings from the captured output"]
+    #[must_use]
+    pub fn parse(&self, output: &str) -> Vec<Finding> {
+        match self {
+            Self::Clippy => clippy(output),
+            Self::Flake8 => flake8(output),
+            Self::GolangciLint => golangci_lint(output),
+            Self::Eslint => eslint(output),
+            Self::CargoAudit => cargo_audit(output),
+            Self::NpmAudit => npm_audit(output),
+            Self::Govulncheck => govulncheck(output),
+        }
+    }
+}
+
+#[doc = "The `lint` script of the `package.json` of the task directory, empty without it"]
+fn lint_script(task: &Task) -> String {
+    read_to_string(task.cwd.clone().unwrap_or_default().join("package.json"))
+        .ok()
+        .and_then(|manifest| serde_json::from_str::<Value>(&manifest).ok())
+        .and_then(|manifest| manifest["scripts"]["lint"].as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[doc = "A problem reported by a linter"]
+pub struct Finding {
+    pub rule: String,
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    #[doc = "`error`, `warning` or `note`"]
+    pub level: String,
+    pub message: String,
+}
+
+#[doc = "The level names accepted by sarif"]
+fn level(level: &str) -> String {
+    match level {
+        "error" | "E" | "F" | "critical" | "high" => String::from("error"),
+        "note" | "help" | "info" | "low" => String::from("note"),
+        _ => String::from("warning"),
+    }
+}
+
+#[doc = "Parse the `cargo clippy --message-format=json` output"]
+fn clippy(output: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+    for line in output.lines() {
+        let Ok(message) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        if message["reason"].ne("compiler-message") {
+            continue;
+        }
+        let diagnostic: &Value = &message["message"];
+        let Some(span) = diagnostic["spans"].as_array().and_then(|spans| {
+            spans
+                .iter()
+                .find(|span| span["is_primary"].as_bool().unwrap_or(false))
+        }) else {
+            continue;
+        };
+        findings.push(Finding {
+            rule: diagnostic["code"]["code"]
+                .as_str()
+                .unwrap_or("rustc")
+                .to_string(),
+            file: span["file_name"].as_str().unwrap_or_default().to_string(),
+            line: span["line_start"].as_u64().map_or(1, |line| line as usize),
+            column: span["column_start"]
+                .as_u64()
+                .map_or(1, |column| column as usize),
+            level: level(diagnostic["level"].as_str().unwrap_or("warning")),
+            message: diagnostic["message"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        });
+    }
+    findings
+}
+
+///
+/// # Rendered
+///
+/// The human-readable text of a `--message-format=json` line of cargo.
+/// The diagnostics give their rendered text, the other messages of cargo are dropped,
+/// and the lines which are not cargo messages are kept as they are.
+///
+#[must_use]
+pub fn rendered(line: &str) -> Option<String> {
+    let Ok(message) = serde_json::from_str::<Value>(line.trim()) else {
+        return Some(line.to_string());
+    };
+    if message["reason"].is_null() {
+        return Some(line.to_string());
+    }
+    if message["reason"].ne("compiler-message") {
+        return None;
+    }
+    message["message"]["rendered"]
+        .as_str()
+        .map(|text| text.trim_end().to_string())
+}
+
+#[must_use]
+#[doc = "The human-readable text of a whole cargo json output"]
+pub fn readable(output: &str) -> String {
+    output
+        .lines()
+        .filter_map(rendered)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[doc = "Split `file:line:column: rest`"]
+fn location(line: &str) -> Option<(String, usize, usize, &str)> {
+    let mut parts = line.splitn(4, ':');
+    let file: &str = parts.next()?;
+    let row: usize = parts.next()?.trim().parse().ok()?;
+    let column: usize = parts.next()?.trim().parse().ok()?;
+    Some((file.to_string(), row, column, parts.next()?.trim()))
+}
+
+#[doc = "Parse the default flake8 output, `file:line:column: CODE message`"]
+fn flake8(output: &str) -> Vec<Finding> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (file, row, column, rest) = location(line)?;
+            let (rule, message) = rest.split_once(' ')?;
+            Some(Finding {
+                rule: rule.to_string(),
+                file,
+                line: row,
+                column,
+                level: level(rule.get(..1).unwrap_or_default()),
+                message: message.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[doc = "Parse the default golangci-lint output, `file:line:column: message (linter)`"]
+fn golangci_lint(output: &str) -> Vec<Finding> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (file, row, column, rest) = location(line)?;
+            let (message, rule) = rest
+                .strip_suffix(')')
+                .and_then(|rest| rest.rsplit_once(" ("))
+                .unwrap_or((rest, "golangci-lint"));
+            Some(Finding {
+                rule: rule.to_string(),
+                file,
+                line: row,
+                column,
+                level: level("warning"),
+                message: message.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[doc = "Parse the eslint stylish output, a file line followed by `line:column level message rule` lines"]
+fn eslint(output: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+    let mut file: Option<String> = None;
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            file = Path::new(line.trim())
+                .extension()
+                .map(|_| line.trim().to_string());
+            continue;
+        }
+        let Some(current) = &file else {
+            continue;
+        };
+        let mut words = line.split_whitespace();
+        let Some((row, column)) = words.next().and_then(|position| position.split_once(':')) else {
+            continue;
+        };
+        let (Ok(row), Ok(column)) = (row.parse::<usize>(), column.parse::<usize>()) else {
+            continue;
+        };
+        let severity: &str = words.next().unwrap_or("warning");
+        let rest: Vec<&str> = words.collect();
+        let Some((rule, message)) = rest.split_last() else {
+            continue;
+        };
+        findings.push(Finding {
+            rule: (*rule).to_string(),
+            file: current.to_string(),
+            line: row,
+            column,
+            level: level(severity),
+            message: message.join(" "),
+        });
+    }
+    findings
+}
+
+#[doc = "A finding of an auditor, located on the manifest of the dependencies"]
+fn advisory(rule: &str, file: &str, level: &str, message: String) -> Finding {
+    Finding {
+        rule: rule.to_string(),
+        file: file.to_string(),
+        line: 1,
+        column: 1,
+        level: self::level(level),
+        message,
+    }
+}
+
+#[doc = "Parse the `cargo audit` report, a `Crate:`, `Version:`, `Title:` and `ID:` block by advisory"]
+fn cargo_audit(output: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+    let mut fields: BTreeMap<&str, &str> = BTreeMap::new();
+    for line in output.lines().chain(["Crate:"]) {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        if key.contains(char::is_whitespace) {
+            continue;
+        }
+        if key.eq("Crate") {
+            if let (Some(id), Some(name)) = (fields.get("ID"), fields.get("Crate")) {
+                findings.push(advisory(
+                    id,
+                    "Cargo.lock",
+                    if fields.contains_key("Warning") {
+                        "warning"
+                    } else {
+                        "error"
+                    },
+                    format!(
+                        "{name} {}: {}",
+                        fields.get("Version").unwrap_or(&""),
+                        fields.get("Title").unwrap_or(&"")
+                    ),
+                ));
+            }
+            fields.clear();
+        }
+        fields.insert(key, value.trim());
+    }
+    findings
+}
+
+#[doc = "Parse the `npm audit` report, a `name  range` line followed by `title - advisory url` lines"]
+fn npm_audit(output: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+    let mut package: &str = "";
+    let mut severity: &str = "warning";
+    for line in output.lines() {
+        if let Some(value) = line.strip_prefix("Severity:") {
+            severity = value.trim();
+        } else if let Some((title, url)) = line.rsplit_once(" - https://") {
+            findings.push(advisory(
+                url.rsplit('/').next().unwrap_or(url),
+                "package-lock.json",
+                severity,
+                format!("{package}: {}", title.trim()),
+            ));
+        } else if let Some((name, _)) = line
+            .split_once("  ")
+            .filter(|(name, _)| !name.is_empty() && !name.contains(char::is_whitespace))
+        {
+            package = name;
+            severity = "warning";
+        }
+    }
+    findings
+}
+
+#[doc = "Parse the `govulncheck` text report, a `Vulnerability #n: ID` block by vulnerability"]
+fn govulncheck(output: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+    let mut called: bool = true;
+    let mut lines = output.lines().map(str::trim).peekable();
+    while let Some(line) = lines.next() {
+        if line.starts_with("=== ") {
+            called = line.contains("Symbol");
+            continue;
+        }
+        let Some((_, id)) = line
+            .strip_prefix("Vulnerability #")
+            .and_then(|rest| rest.split_once(": "))
+        else {
+            continue;
+        };
+        let mut finding: Finding = advisory(
+            id.trim(),
+            "go.mod",
+            if called { "error" } else { "warning" },
+            lines.next().unwrap_or_default().to_string(),
+        );
+        while let Some(detail) = lines.next_if(|next| !next.starts_with("Vulnerability #")) {
+            if let Some(module) = detail.strip_prefix("Found in:") {
+                finding.message = format!("{} ({})", finding.message, module.trim());
+            }
+            if let Some((file, row, column, _)) = detail
+                .strip_prefix("#1:")
+                .and_then(|trace| location(trace.trim()))
+            {
+                finding.file = file;
+                finding.line = row;
+                finding.column = column;
+            }
+            if detail.starts_with("=== ") {
+                called = detail.contains("Symbol");
+            }
+        }
+        findings.push(finding);
+    }
+    findings
+}
+
+///
+/// # Findings
+///
+/// The findings of a task result, the paths are made relative to the current directory
+///
+#[must_use]
+pub fn findings(result: &TaskResult) -> Vec<Finding> {
+    if !result.status.is_executed() {
+        return Vec::new();
+    }
+    let Some(parser) = Parser::detect(&result.task) else {
+        return Vec::new();
+    };
+    let output: String = format!(
+        "{}\n{}",
+        read_to_string(&result.stdout).unwrap_or_default(),
+        read_to_string(&result.stderr).unwrap_or_default()
+    );
+    let base: PathBuf = result.task.cwd.clone().unwrap_or_default();
+    let current: PathBuf = std::env::current_dir().unwrap_or_default();
+    let mut all: Vec<Finding> = parser
+        .parse(&output)
+        .into_iter()
+        .map(|mut finding| {
+            let path: PathBuf = base.join(finding.file.trim_start_matches("./"));
+            finding.file = path
+                .strip_prefix(&current)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+            finding
+        })
+        .collect();
+    all.sort();
+    all.dedup();
+    all
+}
+
+///
+/// # Sarif
+///
+/// Serialize the linter findings as sarif 2.1.0, a run by linter
+///
+/// # Errors
+///
+/// On serialization failure
+///
+pub fn sarif(reports: &[Report]) -> Result<String, Error> {
+    let mut runs: Vec<Value> = Vec::new();
+    for result in reports.iter().flat_map(|report| &report.tasks) {
+        let Some(parser) = Parser::detect(&result.task) else {
+            continue;
+        };
+        let found: Vec<Finding> = findings(result);
+        let rules: BTreeSet<&str> = found.iter().map(|finding| finding.rule.as_str()).collect();
+        runs.push(json!({
+            "tool": {
+                "driver": {
+                    "name": parser.name(),
+                    "informationUri": "https://github.com/hackia/zuu",
+                    "rules": rules.iter().map(|rule| json!({ "id": rule })).collect::<Vec<Value>>(),
+                }
+            },
+            "automationDetails": {
+                "id": format!("{}/{}/{}/", result.project, result.language, result.task.name),
+            },
+            "invocations": [{
+                "executionSuccessful": result.status.is_success(),
+                "properties": { "duration": result.duration.as_secs_f64() },
+            }],
+            "results": found.iter().map(|finding| json!({
+                "ruleId": finding.rule,
+                "level": finding.level,
+                "message": { "text": finding.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": finding.file },
+                        "region": {
+                            "startLine": finding.line,
+                            "startColumn": finding.column,
+                        }
+                    }
+                }]
+            })).collect::<Vec<Value>>(),
+        }));
+    }
+    serde_json::to_string_pretty(&json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": runs,
+    }))
+    .map_err(Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::TaskCategory;
+    use crate::testing::scratch;
+    use std::fs::{remove_dir_all, write};
+
+    #[doc = "The findings of a parser, without the message"]
+    fn located(findings: &[Finding]) -> Vec<(&str, &str, usize, usize, &str)> {
+        findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.rule.as_str(),
+                    finding.file.as_str(),
+                    finding.line,
+                    finding.column,
+                    finding.level.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clippy_reads_the_primary_span_of_the_diagnostics() {
+        let output: &str = include_str!("../tests/fixtures/clippy.json");
+        let findings: Vec<Finding> = Parser::Clippy.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                (
+                    "clippy::cargo_common_metadata",
+                    "src/main.rs",
+                    1,
+                    1,
+                    "error"
+                ),
+                ("clippy::len_zero", "src/main.rs", 3, 8, "error"),
+            ]
+        );
+        assert_eq!(findings[1].message, "length comparison to zero");
+    }
+
+    #[test]
+    fn rendered_gives_the_text_of_the_diagnostics_only() {
+        let output: &str = include_str!("../tests/fixtures/clippy.json");
+        let lines: Vec<Option<String>> = output.lines().map(rendered).collect();
+        assert!(lines[1]
+            .as_deref()
+            .is_some_and(|text| text.starts_with("error: length comparison to zero")));
+        assert_eq!(lines[2], None);
+        assert_eq!(
+            rendered("    Checking a v0.1.0"),
+            Some(String::from("    Checking a v0.1.0"))
+        );
+        assert!(!readable(output).contains("\"reason\""));
+    }
+
+    #[test]
+    fn flake8_reads_the_default_format() {
+        let output: &str = "./app/main.py:1:1: F401 'os' imported but unused
+./app/main.py:10:80: E501 line too long (88 > 79 characters)
+./app/util.py:3:5: W291 trailing whitespace
+";
+        let findings: Vec<Finding> = Parser::Flake8.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                ("F401", "./app/main.py", 1, 1, "error"),
+                ("E501", "./app/main.py", 10, 80, "error"),
+                ("W291", "./app/util.py", 3, 5, "warning"),
+            ]
+        );
+        assert_eq!(findings[1].message, "line too long (88 > 79 characters)");
+    }
+
+    #[test]
+    fn golangci_lint_reads_the_linter_name_and_skips_the_source_lines() {
+        let output: &str = "main.go:12:2: ineffectual assignment to err (ineffassign)
+\terr = nil
+\t^
+pkg/api/server.go:40:15: Error return value of `w.Write` is not checked (errcheck)
+\tw.Write(body)
+\t       ^
+";
+        let findings: Vec<Finding> = Parser::GolangciLint.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                ("ineffassign", "main.go", 12, 2, "warning"),
+                ("errcheck", "pkg/api/server.go", 40, 15, "warning"),
+            ]
+        );
+        assert_eq!(
+            findings[1].message,
+            "Error return value of `w.Write` is not checked"
+        );
+    }
+
+    #[test]
+    fn eslint_reads_the_stylish_format() {
+        let output: &str = "
+/home/user/app/src/index.js
+  1:10  error    'unused' is defined but never used  no-unused-vars
+  3:1   warning  Unexpected console statement        no-console
+
+\u{2716} 2 problems (1 error, 1 warning)
+
+";
+        let findings: Vec<Finding> = Parser::Eslint.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                (
+                    "no-unused-vars",
+                    "/home/user/app/src/index.js",
+                    1,
+                    10,
+                    "error"
+                ),
+                ("no-console", "/home/user/app/src/index.js", 3, 1, "warning"),
+            ]
+        );
+        assert_eq!(findings[0].message, "'unused' is defined but never used");
+    }
+
+    #[test]
+    fn cargo_audit_reads_the_advisory_blocks() {
+        let output: &str =
+            "    Fetching advisory database from `https://github.com/RustSec/advisory-db.git`
+    Scanning Cargo.lock for vulnerabilities (193 crate dependencies)
+Crate:     time
+Version:   0.1.45
+Title:     Potential segfault in the time crate
+Date:      2020-11-18
+ID:        RUSTSEC-2020-0071
+URL:       https://rustsec.org/advisories/RUSTSEC-2020-0071
+Solution:  Upgrade to >=0.2.23
+Dependency tree:
+time 0.1.45
+└── chrono 0.4.19
+
+Crate:     ansi_term
+Version:   0.12.1
+Warning:   unmaintained
+Title:     ansi_term is Unmaintained
+Date:      2021-08-18
+ID:        RUSTSEC-2021-0139
+URL:       https://rustsec.org/advisories/RUSTSEC-2021-0139
+
+error: 1 vulnerability found!
+warning: 1 allowed warning found
+";
+        let findings: Vec<Finding> = Parser::CargoAudit.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                ("RUSTSEC-2020-0071", "Cargo.lock", 1, 1, "error"),
+                ("RUSTSEC-2021-0139", "Cargo.lock", 1, 1, "warning"),
+            ]
+        );
+        assert_eq!(
+            findings[0].message,
+            "time 0.1.45: Potential segfault in the time crate"
+        );
+    }
+
+    #[test]
+    fn npm_audit_reads_the_advisories_of_every_package() {
+        let output: &str = "# npm audit report
+
+minimist  <0.2.1 || >=1.0.0 <1.2.3
+Severity: critical
+Prototype Pollution in minimist - https://github.com/advisories/GHSA-xvch-5gv4-984h
+fix available via `npm audit fix`
+node_modules/minimist
+
+semver  <5.7.2
+Severity: moderate
+semver vulnerable to Regular Expression Denial of Service - https://github.com/advisories/GHSA-c2qf-rxjj-qqgw
+fix available via `npm audit fix`
+node_modules/semver
+
+2 vulnerabilities (1 moderate, 1 critical)
+";
+        let findings: Vec<Finding> = Parser::NpmAudit.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                ("GHSA-xvch-5gv4-984h", "package-lock.json", 1, 1, "error"),
+                ("GHSA-c2qf-rxjj-qqgw", "package-lock.json", 1, 1, "warning"),
+            ]
+        );
+        assert_eq!(
+            findings[0].message,
+            "minimist: Prototype Pollution in minimist"
+        );
+    }
+
+    #[test]
+    fn govulncheck_reads_the_vulnerabilities_and_their_first_trace() {
+        let output: &str = "=== Symbol Results ===
+
+Vulnerability #1: GO-2023-2185
+    Insecure parsing of Windows paths with a \\??\\ prefix in path/filepath
+  More info: https://pkg.go.dev/vuln/GO-2023-2185
+  Standard library
+    Found in: path/filepath@go1.21.3
+    Fixed in: path/filepath@go1.21.4
+    Example traces found:
+      #1: cmd/main.go:10:13: app.main calls filepath.Join
+
+=== Module Results ===
+
+Vulnerability #1: GO-2022-1059
+    Denial of service via crafted Accept-Language header in golang.org/x/text/language
+  More info: https://pkg.go.dev/vuln/GO-2022-1059
+  Module: golang.org/x/text
+    Found in: golang.org/x/text@v0.3.7
+    Fixed in: golang.org/x/text@v0.3.8
+
+Your code is affected by 1 vulnerability from the Go standard library.
+";
+        let findings: Vec<Finding> = Parser::Govulncheck.parse(output);
+        assert_eq!(
+            located(&findings),
+            vec![
+                ("GO-2023-2185", "cmd/main.go", 10, 13, "error"),
+                ("GO-2022-1059", "go.mod", 1, 1, "warning"),
+            ]
+        );
+        assert_eq!(
+            findings[1].message,
+            "Denial of service via crafted Accept-Language header in golang.org/x/text/language (golang.org/x/text@v0.3.7)"
+        );
+    }
+
+    #[test]
+    fn detect_reads_npm_run_lint_as_eslint_only_when_the_script_runs_it() {
+        let directory: PathBuf = scratch("lint-script");
+        let cwd: String = directory.to_string_lossy().to_string();
+        let task: Task =
+            Task::new("lint", TaskCategory::Lint, "", "npm run lint", "", "").with_cwd(&cwd);
+        write(
+            directory.join("package.json"),
+            r#"{"scripts":{"lint":"tsc --noEmit"}}"#,
+        )
+        .expect("the manifest is written");
+        assert_eq!(Parser::detect(&task), None);
+        write(
+            directory.join("package.json"),
+            r#"{"scripts":{"lint":"eslint src"}}"#,
+        )
+        .expect("the manifest is written");
+        assert_eq!(Parser::detect(&task), Some(Parser::Eslint));
+        remove_dir_all(&directory).expect("the directory is removed");
+    }
+}
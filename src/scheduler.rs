@@ -0,0 +1,398 @@
+use crate::interrupt::cancelled;
+use crate::support::{Language, ROOT_PROJECT};
+use crate::task::{Task, TaskResult, TaskStatus};
+use std::path::PathBuf;
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+#[doc = "A task to execute for a language"]
+pub struct Job {
+    #[doc = "The name of the project, `.` for the repository root"]
+    pub project: String,
+    pub language: Language,
+    pub task: Task,
+}
+
+impl Job {
+    #[must_use]
+    #[doc = "The output directory, `zuu/<language>` at the root and `zuu/<project>/<language>` otherwise"]
+    pub fn directory(&self) -> PathBuf {
+        if self.project.eq(ROOT_PROJECT) {
+            PathBuf::from(format!("zuu/{}", self.language))
+        } else {
+            PathBuf::from(format!("zuu/{}/{}", self.project, self.language))
+        }
+    }
+
+    #[must_use]
+    #[doc = "The task name prefixed by its language, and by its project outside the repository root"]
+    pub fn id(&self) -> String {
+        if self.project.eq(ROOT_PROJECT) {
+            format!("{}/{}", self.language, self.task.name)
+        } else {
+            format!("{}/{}/{}", self.project, self.language, self.task.name)
+        }
+    }
+
+    #[must_use]
+    #[doc = "A task message as displayed, prefixed by the project outside the repository root"]
+    pub fn label(&self, message: &str) -> String {
+        if self.project.eq(ROOT_PROJECT) {
+            message.to_string()
+        } else {
+            format!("[{}] {message}", self.project)
+        }
+    }
+
+    #[must_use]
+    #[doc = "The file storing the standard output"]
+    pub fn stdout(&self) -> PathBuf {
+        self.directory()
+            .join("stdout")
+            .join(self.task.output_file())
+    }
+
+    #[must_use]
+    #[doc = "The file storing the error output"]
+    pub fn stderr(&self) -> PathBuf {
+        self.directory()
+            .join("stderr")
+            .join(self.task.output_file())
+    }
+
+    #[must_use]
+    #[doc = "The files keeping the standard and the error output of a failed attempt"]
+    pub fn attempt_outputs(&self, attempt: u32) -> [PathBuf; 2] {
+        ["stdout", "stderr"].map(|output| {
+            self.directory()
+                .join(output)
+                .join(self.task.attempt_file(attempt))
+        })
+    }
+
+    #[must_use]
+    #[doc = "The result of the job without exit code nor duration"]
+    pub fn result(&self, status: TaskStatus) -> TaskResult {
+        TaskResult {
+            project: self.project.to_string(),
+            language: self.language,
+            task: self.task.clone(),
+            command: self.task.command_line(),
+            status,
+            exit_code: None,
+            duration: Duration::ZERO,
+            stdout: self.stdout(),
+            stderr: self.stderr(),
+            cached: false,
+            attempts: 0,
+        }
+    }
+}
+
+#[doc = "Execute the jobs given by the scheduler"]
+pub trait Executor: Sync {
+    #[doc = "Execute the job at the given plan index"]
+    fn execute(&self, index: usize, job: &Job) -> TaskResult;
+
+    #[doc = "Called instead of `execute` when a needed job has not passed"]
+    fn skip(&self, _index: usize, job: &Job, _failed: &[&Job]) -> TaskResult {
+        job.result(TaskStatus::Skipped)
+    }
+}
+
+impl<F> Executor for F
+where
+    F: Fn(usize, &Job) -> TaskResult + Sync,
+{
+    fn execute(&self, index: usize, job: &Job) -> TaskResult {
+        self(index, job)
+    }
+}
+
+#[derive(Default)]
+#[doc = "The progress of the scheduled jobs"]
+struct State {
+    started: Vec<bool>,
+    results: Vec<Option<TaskResult>>,
+    running: usize,
+    stopped: bool,
+}
+
+#[doc = "Execute jobs in parallel while respecting their declared order"]
+pub struct Scheduler {
+    jobs: usize,
+    strict: bool,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new(thread::available_parallelism().map_or(1, usize::from))
+    }
+}
+
+impl Scheduler {
+    #[must_use]
+    #[doc = "Create a scheduler running at most `jobs` tasks at the same time"]
+    pub fn new(jobs: usize) -> Self {
+        Self {
+            jobs: jobs.max(1),
+            strict: false,
+        }
+    }
+
+    #[must_use]
+    #[doc = "Stop starting new jobs after the first failure"]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    ///
+    /// # Dependencies
+    ///
+    /// The index of the jobs to finish before each job, resolved from `Task::after`
+    /// and `Task::needs` inside the same project and language
+    ///
+    #[must_use]
+    pub fn dependencies(plan: &[Job]) -> Vec<Vec<usize>> {
+        Self::resolve(plan, |task| task.prerequisites())
+    }
+
+    #[doc = "The index of the jobs to pass before each job, resolved from `Task::needs`"]
+    #[must_use]
+    pub fn needs(plan: &[Job]) -> Vec<Vec<usize>> {
+        Self::resolve(plan, |task| task.needs.iter().collect())
+    }
+
+    fn resolve(plan: &[Job], names: impl Fn(&Task) -> Vec<&String>) -> Vec<Vec<usize>> {
+        plan.iter()
+            .enumerate()
+            .map(|(index, job)| {
+                let names: Vec<&String> = names(&job.task);
+                plan.iter()
+                    .enumerate()
+                    .filter(|(other, candidate)| {
+                        other.ne(&index)
+                            && candidate.project.eq(&job.project)
+                            && candidate.language.eq(&job.language)
+                            && names.contains(&&candidate.task.name)
+                    })
+                    .map(|(other, _)| other)
+                    .collect()
+            })
+            .collect()
+    }
+
+    ///
+    /// # Run
+    ///
+    /// Execute the plan with the executor, called with the job index.
+    /// A job is skipped when one of its needed jobs has not passed.
+    /// The results are returned in the plan order, the jobs not started in strict mode
+    /// or after the cancellation are cancelled.
+    ///
+    /// # Panics
+    ///
+    /// If a worker thread panics
+    ///
+    pub fn run<E: Executor>(&self, plan: &[Job], executor: &E) -> Vec<TaskResult> {
+        let dependencies: Vec<Vec<usize>> = Self::dependencies(plan);
+        let needs: Vec<Vec<usize>> = Self::needs(plan);
+        let state: Mutex<State> = Mutex::new(State {
+            started: vec![false; plan.len()],
+            results: vec![None; plan.len()],
+            ..State::default()
+        });
+        let changed: Condvar = Condvar::new();
+        thread::scope(|scope| {
+            for _ in 0..self.jobs.min(plan.len()) {
+                scope.spawn(|| loop {
+                    let mut current = state.lock().unwrap_or_else(PoisonError::into_inner);
+                    let index: usize = loop {
+                        if current.stopped
+                            || cancelled()
+                            || current.started.iter().all(|started| *started)
+                        {
+                            return;
+                        }
+                        if let Some(index) = Self::next(&current, &dependencies) {
+                            break index;
+                        }
+                        current = changed
+                            .wait(current)
+                            .unwrap_or_else(PoisonError::into_inner);
+                    };
+                    current.started[index] = true;
+                    current.running += 1;
+                    let failed: Vec<&Job> = needs[index]
+                        .iter()
+                        .filter(|need| {
+                            current.results[**need]
+                                .as_ref()
+                                .is_none_or(|result| !result.status.is_success())
+                        })
+                        .map(|need| &plan[*need])
+                        .collect();
+                    drop(current);
+
+                    let result: TaskResult = if failed.is_empty() {
+                        executor.execute(index, &plan[index])
+                    } else {
+                        executor.skip(index, &plan[index], &failed)
+                    };
+
+                    let mut current = state.lock().unwrap_or_else(PoisonError::into_inner);
+                    current.running -= 1;
+                    if self.strict && result.status.is_failure() {
+                        current.stopped = true;
+                    }
+                    current.results[index] = Some(result);
+                    changed.notify_all();
+                });
+            }
+        });
+        state
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .results
+            .into_iter()
+            .zip(plan)
+            .map(|(result, job)| result.unwrap_or_else(|| job.result(TaskStatus::Cancelled)))
+            .collect()
+    }
+
+    ///
+    /// # Next
+    ///
+    /// The first job not started with all its dependencies finished.
+    /// When nothing runs and nothing is ready the remaining jobs wait on each other,
+    /// the first of them is started to break the cycle.
+    ///
+    fn next(state: &State, dependencies: &[Vec<usize>]) -> Option<usize> {
+        let mut pending = state
+            .started
+            .iter()
+            .enumerate()
+            .filter(|(_, started)| !**started)
+            .map(|(index, _)| index);
+        let ready = pending.clone().find(|index| {
+            dependencies[*index]
+                .iter()
+                .all(|dependency| state.results[*dependency].is_some())
+        });
+        if ready.is_none() && state.running.eq(&0) {
+            return pending.next();
+        }
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::TaskCategory;
+
+    fn state(started: &[bool], finished: &[bool], running: usize) -> State {
+        let job: Job = Job {
+            project: ROOT_PROJECT.to_string(),
+            language: Language::Rust,
+            task: Task::new("test", TaskCategory::Test, "", "true", "", ""),
+        };
+        State {
+            started: started.to_vec(),
+            results: finished
+                .iter()
+                .map(|done| done.then(|| job.result(TaskStatus::Pass)))
+                .collect(),
+            running,
+            stopped: false,
+        }
+    }
+
+    #[test]
+    fn next_starts_the_first_ready_job() {
+        let dependencies: Vec<Vec<usize>> = vec![vec![], vec![0], vec![]];
+        let running = state(&[true, false, false], &[false, false, false], 1);
+        assert_eq!(Scheduler::next(&running, &dependencies), Some(2));
+        let finished = state(&[true, false, false], &[true, false, false], 0);
+        assert_eq!(Scheduler::next(&finished, &dependencies), Some(1));
+        let waiting = state(&[true, false, true], &[false, false, false], 2);
+        assert_eq!(Scheduler::next(&waiting, &dependencies), None);
+    }
+
+    #[test]
+    fn next_breaks_a_cycle_when_nothing_runs() {
+        let dependencies: Vec<Vec<usize>> = vec![vec![1], vec![0]];
+        assert_eq!(
+            Scheduler::next(&state(&[false, false], &[false, false], 0), &dependencies),
+            Some(0)
+        );
+        assert_eq!(
+            Scheduler::next(&state(&[true, false], &[false, false], 1), &dependencies),
+            None
+        );
+    }
+
+    fn plan(tasks: Vec<Task>) -> Vec<Job> {
+        tasks
+            .into_iter()
+            .map(|task| Job {
+                project: ROOT_PROJECT.to_string(),
+                language: Language::Rust,
+                task,
+            })
+            .collect()
+    }
+
+    fn statuses(results: &[TaskResult]) -> Vec<(&str, TaskStatus)> {
+        results
+            .iter()
+            .map(|result| (result.task.name.as_str(), result.status))
+            .collect()
+    }
+
+    fn failing(_: usize, job: &Job) -> TaskResult {
+        job.result(if job.task.name.eq("structure") {
+            TaskStatus::Fail
+        } else {
+            TaskStatus::Pass
+        })
+    }
+
+    #[test]
+    fn run_skips_the_jobs_whose_needs_have_not_passed() {
+        let jobs: Vec<Job> = plan(vec![
+            Task::new("structure", TaskCategory::Structure, "", "true", "", ""),
+            Task::new("test", TaskCategory::Test, "", "true", "", "").with_needs(&["structure"]),
+            Task::new("format", TaskCategory::Format, "", "true", "", "")
+                .with_after(&["structure"]),
+        ]);
+        assert_eq!(
+            statuses(&Scheduler::new(4).run(&jobs, &failing)),
+            vec![
+                ("structure", TaskStatus::Fail),
+                ("test", TaskStatus::Skipped),
+                ("format", TaskStatus::Pass)
+            ]
+        );
+    }
+
+    #[test]
+    fn run_cancels_the_jobs_not_started_after_a_strict_failure() {
+        let jobs: Vec<Job> = plan(vec![
+            Task::new("structure", TaskCategory::Structure, "", "true", "", ""),
+            Task::new("test", TaskCategory::Test, "", "true", "", ""),
+            Task::new("format", TaskCategory::Format, "", "true", "", ""),
+        ]);
+        assert_eq!(
+            statuses(&Scheduler::new(1).strict(true).run(&jobs, &failing)),
+            vec![
+                ("structure", TaskStatus::Fail),
+                ("test", TaskStatus::Cancelled),
+                ("format", TaskStatus::Cancelled)
+            ]
+        );
+    }
+}
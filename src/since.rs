@@ -0,0 +1,114 @@
+use crate::scheduler::Job;
+use crate::staged::{git, paths};
+use crate::support::{language_of, Language, Project};
+use crate::task::TaskCategory;
+use std::io::Error;
+use std::path::{Path, PathBuf};
+
+#[doc = "The task categories checking the whole project, they run whatever the changes"]
+pub const WHOLE_PROJECT: [TaskCategory; 2] = [TaskCategory::Audit, TaskCategory::Outdated];
+
+///
+/// # Changed since
+///
+/// The files changed between the merge base of the reference and the worktree,
+/// deleted and untracked files included, relative to the current directory
+///
+/// # Errors
+///
+/// Outside of a git repository or on an unknown reference
+///
+pub fn changed_since(reference: &str) -> Result<Vec<PathBuf>, Error> {
+    let base: String = String::from_utf8_lossy(&git(&["merge-base", reference, "HEAD"])?)
+        .trim()
+        .to_string();
+    let mut changed: Vec<PathBuf> = paths(&git(&[
+        "diff",
+        "--name-only",
+        "--no-renames",
+        "--relative",
+        "-z",
+        base.as_str(),
+    ])?);
+    for path in paths(&git(&["ls-files", "--others", "--exclude-standard", "-z"])?) {
+        if !changed.contains(&path) {
+            changed.push(path);
+        }
+    }
+    Ok(changed)
+}
+
+#[must_use]
+#[doc = "Check if the job runs for the affected projects and languages"]
+pub fn is_affected(job: &Job, keys: &[(String, Language)]) -> bool {
+    WHOLE_PROJECT.contains(&job.task.category)
+        || keys
+            .iter()
+            .any(|(project, language)| job.project.eq(project) && job.language.eq(language))
+}
+
+///
+/// # Widened
+///
+/// The projects and languages of the changed files of no language, a lockfile or a build script,
+/// every task of them runs on the whole project.
+/// A file outside of the sub-projects widens them all.
+///
+#[must_use]
+pub fn widened(projects: &[Project], changed: &[PathBuf]) -> Vec<(String, Language)> {
+    let owned = |path: &Path| {
+        projects
+            .iter()
+            .any(|project| !project.is_root() && path.starts_with(&project.root))
+    };
+    let mut keys: Vec<(String, Language)> = Vec::new();
+    for path in changed.iter().filter(|path| language_of(path).is_none()) {
+        for project in projects {
+            if project.is_root() || path.starts_with(&project.root) || !owned(path) {
+                for language in &project.languages {
+                    let key: (String, Language) = (project.name.to_string(), *language);
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::ROOT_PROJECT;
+
+    fn project(name: &str, languages: &[Language]) -> Project {
+        Project {
+            name: name.to_string(),
+            root: PathBuf::from(if name.eq(ROOT_PROJECT) { "" } else { name }),
+            languages: languages.to_vec(),
+        }
+    }
+
+    #[test]
+    fn widened_keeps_the_files_of_a_language_targeted() {
+        let projects: Vec<Project> = vec![project(ROOT_PROJECT, &[Language::Rust])];
+        assert!(widened(&projects, &[PathBuf::from("src/main.rs")]).is_empty());
+    }
+
+    #[test]
+    fn widened_runs_every_language_of_the_project_of_a_lockfile() {
+        let projects: Vec<Project> = vec![
+            project("api", &[Language::Rust, Language::Bash]),
+            project("web", &[Language::TypeScript]),
+        ];
+        assert_eq!(
+            widened(&projects, &[PathBuf::from("api/Cargo.lock")]),
+            vec![
+                (String::from("api"), Language::Rust),
+                (String::from("api"), Language::Bash)
+            ]
+        );
+        assert_eq!(widened(&projects, &[PathBuf::from("Makefile")]).len(), 3);
+    }
+}
@@ -0,0 +1,333 @@
+use std::fs::{create_dir_all, remove_dir_all, remove_file, rename, write};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[doc = "The patch holding the unstaged changes, inside the git directory"]
+pub const UNSTAGED_PATCH: &str = "tux-unstaged.patch";
+
+#[doc = "The directory holding the untracked files, inside the git directory"]
+pub const UNTRACKED_DIRECTORY: &str = "tux-untracked";
+
+#[doc = "The untracked files of the current directory kept in place, the configuration and the reports"]
+pub const KEPT_UNTRACKED: [&str; 2] = ["tux.toml", "zuu"];
+
+#[doc = "The changes hidden during a run, relative to the top level of the repository"]
+struct Pending {
+    root: PathBuf,
+    patch: Option<PathBuf>,
+    backup: PathBuf,
+    untracked: Vec<PathBuf>,
+}
+
+#[doc = "The changes to put back, none when the tree is restored"]
+static PENDING: Mutex<Option<Pending>> = Mutex::new(None);
+
+#[doc = "Take the pending changes until the guard is dropped"]
+fn pending() -> MutexGuard<'static, Option<Pending>> {
+    PENDING.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[doc = "Run git, the output is returned on success"]
+pub(crate) fn git(args: &[&str]) -> Result<Vec<u8>, Error> {
+    let output = Command::new("git")
+        .args(args)
+        .stdin(Stdio::null())
+        .output()?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(Error::other(format!(
+            "git {} => {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+#[doc = "The trimmed output of git, none on failure or empty output"]
+pub(crate) fn revision(args: &[&str]) -> Option<String> {
+    git(args)
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output).trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+#[doc = "Run git from the top level of the repository, whatever the current directory"]
+fn git_at(root: &Path, args: &[&str]) -> Result<Vec<u8>, Error> {
+    let root: String = root.to_string_lossy().to_string();
+    let mut argv: Vec<&str> = vec!["-C", root.as_str()];
+    argv.extend_from_slice(args);
+    git(&argv)
+}
+
+#[doc = "The trimmed path printed by git rev-parse"]
+fn rev_parse(option: &str) -> Result<PathBuf, Error> {
+    Ok(PathBuf::from(
+        String::from_utf8_lossy(&git(&["rev-parse", option])?)
+            .trim()
+            .to_string(),
+    ))
+}
+
+///
+/// # Staged files
+///
+/// The added, copied, modified or renamed files of the index, relative to the current directory
+///
+/// # Errors
+///
+/// Outside of a git repository
+///
+pub fn staged_files() -> Result<Vec<PathBuf>, Error> {
+    let output: Vec<u8> = git(&[
+        "diff",
+        "--cached",
+        "--name-only",
+        "--diff-filter=ACMR",
+        "--relative",
+        "-z",
+    ])?;
+    Ok(paths(&output))
+}
+
+#[doc = "The paths of a git output separated by NUL bytes, `-z`"]
+pub(crate) fn paths(output: &[u8]) -> Vec<PathBuf> {
+    output
+        .split(|byte| byte.eq(&0))
+        .filter(|name| !name.is_empty())
+        .map(|name| PathBuf::from(String::from_utf8_lossy(name).to_string()))
+        .collect()
+}
+
+#[doc = "Put back the worktree of the tracked files as in the index"]
+fn checkout(root: &Path) -> Result<(), Error> {
+    git_at(root, &["-c", "submodule.recurse=0", "checkout", "--", ":/"]).map(|_| ())
+}
+
+#[doc = "Move a file from a directory to another, never over an existing file"]
+fn relocate(file: &Path, from: &Path, to: &Path) -> Result<(), Error> {
+    let target: PathBuf = to.join(file);
+    if target.symlink_metadata().is_ok() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", target.display()),
+        ));
+    }
+    if let Some(parent) = target.parent() {
+        create_dir_all(parent)?;
+    }
+    rename(from.join(file), target)
+}
+
+///
+/// # Restore
+///
+/// Apply the unstaged changes back and move the untracked files back.
+/// The changes made by the tasks are dropped when they conflict with them,
+/// the patch and the untracked files are kept when they can not be put back in full.
+///
+/// # Errors
+///
+/// When the patch can not be applied, or when a task has created an untracked file again
+///
+pub fn restore() -> Result<(), Error> {
+    let mut pending = pending();
+    let Some(hidden) = pending.take() else {
+        return Ok(());
+    };
+    let mut failures: Vec<String> = Vec::new();
+    if let Some(patch) = &hidden.patch {
+        let path: &str = &patch.to_string_lossy();
+        let apply: [&str; 4] = ["apply", "--whitespace=nowarn", "--binary", path];
+        if git_at(&hidden.root, &apply).is_err()
+            && (checkout(&hidden.root).is_err() || git_at(&hidden.root, &apply).is_err())
+        {
+            failures.push(format!("apply the unstaged changes with git apply {path}"));
+        } else {
+            remove_file(patch)?;
+        }
+    }
+    let kept: usize = hidden
+        .untracked
+        .iter()
+        .filter(|file| relocate(file, &hidden.backup, &hidden.root).is_err())
+        .count();
+    if kept > 0 {
+        failures.push(format!(
+            "move the {kept} untracked files left in {} back",
+            hidden.backup.display()
+        ));
+    } else if hidden.backup.exists() {
+        remove_dir_all(&hidden.backup)?;
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::other(format!(
+            "Failed to restore the unstaged changes, {}",
+            failures.join(", and ")
+        )))
+    }
+}
+
+#[doc = "The unstaged and untracked changes hidden during a run, they are put back when dropped"]
+pub struct Snapshot;
+
+impl Snapshot {
+    ///
+    /// # Take
+    ///
+    /// Save the unstaged changes of the tracked files in a patch, move the untracked files
+    /// into the git directory, then make the worktree match the index.
+    /// Git runs from the top level, the whole repository is hidden from any directory.
+    ///
+    /// # Errors
+    ///
+    /// Outside of a git repository, or when the changes of a previous run were not put back
+    ///
+    pub fn take() -> Result<Self, Error> {
+        let root: PathBuf = rev_parse("--show-toplevel")?;
+        let directory: PathBuf = rev_parse("--absolute-git-dir")?;
+        let patch: PathBuf = directory.join(UNSTAGED_PATCH);
+        let backup: PathBuf = directory.join(UNTRACKED_DIRECTORY);
+        if patch.exists() || backup.exists() {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!(
+                    "The changes of a previous run are waiting in {} and {}, apply the patch with git apply and move the files back",
+                    patch.display(),
+                    backup.display()
+                ),
+            ));
+        }
+        let diff: Vec<u8> = git_at(
+            &root,
+            &[
+                "diff",
+                "--binary",
+                "--no-color",
+                "--no-ext-diff",
+                "--ignore-submodules",
+            ],
+        )?;
+        let prefix: String = String::from_utf8_lossy(&git(&["rev-parse", "--show-prefix"])?)
+            .trim()
+            .to_string();
+        let kept: Vec<String> = KEPT_UNTRACKED
+            .iter()
+            .map(|name| format!(":(top,exclude){prefix}{name}"))
+            .collect();
+        let mut listing: Vec<&str> = vec![
+            "ls-files",
+            "--others",
+            "--exclude-standard",
+            "-z",
+            "--",
+            ":/",
+        ];
+        listing.extend(kept.iter().map(String::as_str));
+        let untracked: Vec<PathBuf> = paths(&git_at(&root, &listing)?);
+        if diff.is_empty() && untracked.is_empty() {
+            return Ok(Self);
+        }
+        let mut pending = pending();
+        let hidden: &mut Pending = pending.insert(Pending {
+            root: root.clone(),
+            patch: None,
+            backup: backup.clone(),
+            untracked: Vec::new(),
+        });
+        let mut result: Result<(), Error> = Ok(());
+        if !diff.is_empty() {
+            result = write(&patch, diff);
+            if result.is_ok() {
+                hidden.patch = Some(patch);
+            }
+        }
+        for file in untracked {
+            if result.is_err() {
+                break;
+            }
+            result = relocate(&file, &root, &backup);
+            if result.is_ok() {
+                hidden.untracked.push(file);
+            }
+        }
+        let hide: bool = hidden.patch.is_some();
+        drop(pending);
+        if result.is_ok() && hide {
+            result = checkout(&root);
+        }
+        if let Err(error) = result {
+            let _ = restore();
+            return Err(error);
+        }
+        Ok(Self)
+    }
+
+    ///
+    /// # Restore
+    ///
+    /// Put the unstaged changes and the untracked files back
+    ///
+    /// # Errors
+    ///
+    /// When the patch can not be applied
+    ///
+    pub fn restore(self) -> Result<(), Error> {
+        restore()
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        if let Err(error) = restore() {
+            eprintln!("{error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{git, repository, within};
+    use std::fs::read_to_string;
+
+    #[test]
+    fn snapshot_hides_the_unstaged_and_untracked_changes_until_restored() {
+        let root: PathBuf = repository("staged");
+        write(root.join("tracked.txt"), "committed\n").expect("the file is written");
+        git(&root, &["add", "tracked.txt"]);
+        git(&root, &["commit", "-q", "-m", "first"]);
+        write(root.join("tracked.txt"), "staged\n").expect("the file is written");
+        git(&root, &["add", "tracked.txt"]);
+        write(root.join("tracked.txt"), "unstaged\n").expect("the file is written");
+        create_dir_all(root.join("sub")).expect("the directory is created");
+        write(root.join("sub/untracked.txt"), "untracked\n").expect("the file is written");
+        write(root.join("tux.toml"), "strict = false\n").expect("the file is written");
+        within(&root, || {
+            let snapshot: Snapshot = Snapshot::take().expect("the changes are hidden");
+            assert_eq!(
+                read_to_string("tracked.txt").expect("the file is read"),
+                "staged\n"
+            );
+            assert!(!Path::new("sub/untracked.txt").exists());
+            assert!(Path::new("tux.toml").exists());
+            assert!(Snapshot::take().is_err());
+            snapshot.restore().expect("the changes are put back");
+        });
+        assert_eq!(
+            read_to_string(root.join("tracked.txt")).expect("the file is read"),
+            "unstaged\n"
+        );
+        assert_eq!(
+            read_to_string(root.join("sub/untracked.txt")).expect("the file is read"),
+            "untracked\n"
+        );
+        assert!(!root.join(".git").join(UNSTAGED_PATCH).exists());
+        assert!(!root.join(".git").join(UNTRACKED_DIRECTORY).exists());
+        remove_dir_all(&root).expect("the repository is removed");
+    }
+}
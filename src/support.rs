@@ -1,6 +1,32 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-#[derive(PartialEq)]
+#[doc = "The directories never scanned by the detection"]
+pub const IGNORED_DIRECTORIES: [&str; 8] = [
+    "target",
+    "node_modules",
+    "vendor",
+    "zuu",
+    "build",
+    "dist",
+    "_build",
+    "deps",
+];
+
+#[doc = "The name of the project at the root of the repository"]
+pub const ROOT_PROJECT: &str = ".";
+
+#[doc = "The depth of the directories scanned by the detection"]
+pub const DETECTION_DEPTH: usize = 5;
+
+#[doc = "The number of source files making an extension detection certain"]
+pub const SOURCE_FILES_THRESHOLD: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub enum Language {
     Rust,
     JavaScript,
@@ -50,7 +76,7 @@ impl Display for Language {
             Language::Perl => write!(f, "Perl"),
             Language::R => write!(f, "R"),
             Language::Haskell => write!(f, "Haskell"),
-            Language::Lua => write!(f, "Variant1"),
+            Language::Lua => write!(f, "Lua"),
             Language::ObjectiveC => write!(f, "ObjectiveC"),
             Language::C => write!(f, "C"),
             Language::Cpp => write!(f, "Cpp"),
@@ -67,6 +93,330 @@ impl Display for Language {
     }
 }
 
+impl FromStr for Language {
+    type Err = String;
+
+    #[doc = "Parse a language name ignoring the case, `C++` and `F#` are accepted"]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: &str = match s.trim() {
+            "C++" | "c++" => "Cpp",
+            "F#" | "f#" => "FSharp",
+            name => name,
+        };
+        Support::new()
+            .supported()
+            .into_iter()
+            .find(|language| language.to_string().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("{s} is not a supported language"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[doc = "How sure the detection of a language is"]
+pub enum Confidence {
+    #[doc = "A few source files"]
+    Low,
+    #[doc = "Many source files"]
+    Medium,
+    #[doc = "A project manifest"]
+    High,
+}
+
+impl Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Confidence::Low => write!(f, "low"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::High => write!(f, "high"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[doc = "A language found in the project tree"]
+pub struct Detection {
+    pub language: Language,
+    pub confidence: Confidence,
+    #[doc = "The shallowest directory holding the manifest, or the first source file"]
+    pub location: PathBuf,
+    #[doc = "The number of source files with an extension of the language"]
+    pub files: usize,
+}
+
+impl Detection {
+    #[must_use]
+    #[doc = "Check if the language has a manifest or enough source files, a few stray files are not enough"]
+    pub fn is_certain(&self) -> bool {
+        self.confidence > Confidence::Low
+    }
+}
+
+#[doc = "The language of a project manifest, `package.json` is TypeScript beside a `tsconfig.json`"]
+fn manifest(path: &Path) -> Option<Language> {
+    let name: &str = path.file_name()?.to_str()?;
+    let language: Language = match name {
+        "Cargo.toml" => Language::Rust,
+        "package.json" if path.with_file_name("tsconfig.json").exists() => Language::TypeScript,
+        "package.json" => Language::JavaScript,
+        "tsconfig.json" => Language::TypeScript,
+        "go.mod" => Language::Go,
+        "dub.json" | "dub.sdl" => Language::D,
+        "pyproject.toml" | "setup.py" | "setup.cfg" | "requirements.txt" | "Pipfile" => {
+            Language::Python
+        }
+        "composer.json" => Language::Php,
+        "pom.xml" | "build.gradle" => Language::Java,
+        "build.gradle.kts" => Language::Kotlin,
+        "Package.swift" => Language::Swift,
+        "build.sbt" => Language::Scala,
+        "Gemfile" => Language::Ruby,
+        "Makefile.PL" | "Build.PL" | "cpanfile" => Language::Perl,
+        "DESCRIPTION" => Language::R,
+        "stack.yaml" => Language::Haskell,
+        "Podfile" => Language::ObjectiveC,
+        "shard.yml" => Language::Crystal,
+        "pubspec.yaml" => Language::Dart,
+        "mix.exs" => Language::Elixir,
+        _ => match path.extension()?.to_str()? {
+            "gemspec" => Language::Ruby,
+            "cabal" => Language::Haskell,
+            "rockspec" => Language::Lua,
+            "nimble" => Language::Nim,
+            "fsproj" => Language::FSharp,
+            _ => return None,
+        },
+    };
+    Some(language)
+}
+
+#[doc = "The language of a source file from its extension"]
+fn source(path: &Path) -> Option<Language> {
+    let language: Language = match path.extension()?.to_str()? {
+        "rs" => Language::Rust,
+        "js" | "mjs" | "cjs" | "jsx" => Language::JavaScript,
+        "ts" | "tsx" => Language::TypeScript,
+        "go" => Language::Go,
+        "d" => Language::D,
+        "py" => Language::Python,
+        "php" => Language::Php,
+        "java" => Language::Java,
+        "kt" | "kts" => Language::Kotlin,
+        "swift" => Language::Swift,
+        "scala" => Language::Scala,
+        "rb" => Language::Ruby,
+        "pl" | "pm" => Language::Perl,
+        "R" | "r" => Language::R,
+        "hs" => Language::Haskell,
+        "lua" => Language::Lua,
+        "m" | "mm" => Language::ObjectiveC,
+        "c" | "h" => Language::C,
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" => Language::Cpp,
+        "nim" => Language::Nim,
+        "cr" => Language::Crystal,
+        "fs" | "fsx" => Language::FSharp,
+        "dart" => Language::Dart,
+        "ex" | "exs" => Language::Elixir,
+        "sh" | "bash" => Language::Bash,
+        "zsh" => Language::Zsh,
+        "fish" => Language::Fish,
+        _ => return None,
+    };
+    Some(language)
+}
+
+#[doc = "Check if the directory of the file is a TypeScript one, with a `tsconfig.json` or TypeScript sources"]
+fn typescript(path: &Path) -> bool {
+    if path.with_file_name("tsconfig.json").exists() {
+        return true;
+    }
+    let Some(Ok(entries)) = path.parent().map(read_dir) else {
+        return false;
+    };
+    entries
+        .filter_map(Result::ok)
+        .any(|entry| source(&entry.path()).eq(&Some(Language::TypeScript)))
+}
+
+#[must_use]
+#[doc = "The language of a manifest or of a source file"]
+pub fn language_of(path: &Path) -> Option<Language> {
+    manifest(path).or_else(|| source(path))
+}
+
+#[doc = "Visit the files of the tree in a stable order, hidden and ignored directories excluded"]
+fn walk(directory: &Path, depth: usize, visit: &mut impl FnMut(&Path)) {
+    let Ok(entries) = read_dir(directory) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+    for path in &paths {
+        if path.is_file() {
+            visit(path);
+        }
+    }
+    if depth.eq(&0) {
+        return;
+    }
+    for path in paths.iter().filter(|path| path.is_dir()) {
+        let name: String = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if name.starts_with('.') || IGNORED_DIRECTORIES.contains(&name.as_str()) {
+            continue;
+        }
+        walk(path, depth - 1, visit);
+    }
+}
+
+///
+/// # Detect
+///
+/// Scan the tree for project manifests and source files,
+/// the languages found are returned in the support order.
+/// The JavaScript files of a TypeScript directory, like its configuration files, count as TypeScript.
+///
+#[must_use]
+pub fn detect(root: &Path) -> Vec<Detection> {
+    let mut found: Vec<Detection> = Vec::new();
+    walk(root, DETECTION_DEPTH, &mut |path| {
+        let from_manifest: Option<Language> = manifest(path);
+        let Some(mut language) = from_manifest.or_else(|| source(path)) else {
+            return;
+        };
+        if language.eq(&Language::JavaScript) && typescript(path) {
+            language = Language::TypeScript;
+        }
+        let confidence: Confidence = if from_manifest.is_some() {
+            Confidence::High
+        } else {
+            Confidence::Low
+        };
+        let location: PathBuf = path
+            .parent()
+            .and_then(|parent| parent.strip_prefix(root).ok())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let files: usize = usize::from(from_manifest.is_none());
+        match found
+            .iter_mut()
+            .find(|detection| detection.language.eq(&language))
+        {
+            None => found.push(Detection {
+                language,
+                confidence,
+                location,
+                files,
+            }),
+            Some(detection) => {
+                detection.files += files;
+                if confidence > detection.confidence
+                    || (confidence.eq(&detection.confidence)
+                        && location.components().count() < detection.location.components().count())
+                {
+                    detection.confidence = confidence;
+                    detection.location = location;
+                }
+                if detection.confidence.eq(&Confidence::Low)
+                    && detection.files >= SOURCE_FILES_THRESHOLD
+                {
+                    detection.confidence = Confidence::Medium;
+                }
+            }
+        }
+    });
+    Support::new()
+        .supported()
+        .into_iter()
+        .filter_map(|language| {
+            found
+                .iter()
+                .find(|detection| detection.language.eq(&language))
+                .cloned()
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[doc = "A directory checked with its own languages"]
+pub struct Project {
+    #[doc = "The key of the reports and of the `zuu/<project>` output"]
+    pub name: String,
+    #[doc = "The directory where the tasks are executed"]
+    pub root: PathBuf,
+    pub languages: Vec<Language>,
+}
+
+impl Project {
+    #[must_use]
+    #[doc = "Check if the project is the repository root"]
+    pub fn is_root(&self) -> bool {
+        self.name.eq(ROOT_PROJECT)
+    }
+}
+
+///
+/// # Discover
+///
+/// A project for each directory holding a manifest, the paths are relative to the root.
+/// A directory is ignored for the languages already checked by a parent project,
+/// like the members of a cargo workspace.
+///
+#[must_use]
+pub fn discover(root: &Path) -> Vec<Project> {
+    let mut manifests: BTreeMap<PathBuf, Vec<Language>> = BTreeMap::new();
+    walk(root, DETECTION_DEPTH, &mut |path| {
+        let Some(language) = manifest(path) else {
+            return;
+        };
+        let directory: PathBuf = path
+            .parent()
+            .and_then(|parent| parent.strip_prefix(root).ok())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let languages: &mut Vec<Language> = manifests.entry(directory).or_default();
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    });
+    let mut projects: Vec<Project> = Vec::new();
+    for (directory, languages) in manifests {
+        let languages: Vec<Language> = Support::new()
+            .supported()
+            .into_iter()
+            .filter(|language| {
+                languages.contains(language)
+                    && !projects.iter().any(|parent| {
+                        directory.starts_with(&parent.root) && parent.languages.contains(language)
+                    })
+            })
+            .collect();
+        if languages.is_empty() {
+            continue;
+        }
+        let (name, root): (String, PathBuf) = if directory.as_os_str().is_empty() {
+            (ROOT_PROJECT.to_string(), PathBuf::new())
+        } else {
+            (directory.to_string_lossy().to_string(), directory)
+        };
+        projects.push(Project {
+            name,
+            root,
+            languages,
+        });
+    }
+    for project in &mut projects {
+        if project.is_root() {
+            project.root = PathBuf::from(ROOT_PROJECT);
+        }
+    }
+    projects
+}
+
 #[derive(Default)]
 pub struct Support {
     pub languages: Vec<Language>,
@@ -110,40 +460,11 @@ impl Support {
     #[must_use]
     #[doc = "Get all supported languages"]
     pub fn all(self) -> Vec<String> {
-        let mut data: Vec<String> = Vec::new();
-        for language in &self.languages {
-            match language {
-                Language::Rust => data.push(String::from("Rust")),
-                Language::JavaScript => data.push(String::from("Javascript")),
-                Language::TypeScript => data.push(String::from("TypeScript")),
-                Language::Go => data.push(String::from("Go")),
-                Language::D => data.push(String::from("D")),
-                Language::Python => data.push(String::from("Python")),
-                Language::Php => data.push(String::from("Php")),
-                Language::Java => data.push(String::from("Java")),
-                Language::Kotlin => data.push(String::from("Kotlin")),
-                Language::Swift => data.push(String::from("Swift")),
-                Language::Scala => data.push(String::from("Scala")),
-                Language::Ruby => data.push(String::from("Ruby")),
-                Language::Perl => data.push(String::from("Perl")),
-                Language::R => data.push(String::from("R")),
-                Language::Haskell => data.push(String::from("Haskell")),
-                Language::Lua => data.push(String::from("Lua")),
-                Language::ObjectiveC => data.push(String::from("ObjectiveC")),
-                Language::C => data.push(String::from("C")),
-                Language::Cpp => data.push(String::from("Cpp")),
-                Language::Nim => data.push(String::from("Nim")),
-                Language::Crystal => data.push(String::from("Crystal")),
-                Language::FSharp => data.push(String::from("Fsharp")),
-                Language::Dart => data.push(String::from("Dart")),
-                Language::Elixir => data.push(String::from("Elixir")),
-                Language::Bash => data.push(String::from("Bash")),
-                Language::Zsh => data.push(String::from("Zsh")),
-                Language::Fish => data.push(String::from("Fish")),
-                Language::Unknown => continue,
-            }
-        }
-        data
+        self.languages
+            .iter()
+            .filter(|language| language.ne(&&Language::Unknown))
+            .map(ToString::to_string)
+            .collect()
     }
     #[must_use]
     #[doc = "Get all supported language"]
@@ -151,3 +472,97 @@ impl Support {
         self.languages
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch;
+    use std::fs::{create_dir_all, write};
+
+    #[doc = "Create the files of the tree, with their parent directories"]
+    fn tree(name: &str, files: &[&str]) -> PathBuf {
+        let root: PathBuf = scratch(name);
+        for file in files {
+            let path: PathBuf = root.join(file);
+            create_dir_all(path.parent().expect("the file has a parent")).expect("mkdir");
+            write(&path, "").expect("the file is written");
+        }
+        root
+    }
+
+    fn languages(detected: &[Detection]) -> Vec<Language> {
+        detected
+            .iter()
+            .map(|detection| detection.language)
+            .collect()
+    }
+
+    #[test]
+    fn typescript_takes_over_the_javascript_configuration() {
+        let root: PathBuf = tree(
+            "detect-typescript",
+            &[
+                "package.json",
+                "tsconfig.json",
+                "eslint.config.js",
+                "jest.config.cjs",
+                "src/index.ts",
+            ],
+        );
+        let detected: Vec<Detection> = detect(&root);
+        assert_eq!(languages(&detected), vec![Language::TypeScript]);
+        assert_eq!(detected[0].confidence, Confidence::High);
+        assert_eq!(detected[0].files, 3);
+        let sources: PathBuf = tree(
+            "detect-typescript-sources",
+            &["index.ts", "webpack.config.js"],
+        );
+        assert_eq!(languages(&detect(&sources)), vec![Language::TypeScript]);
+    }
+
+    #[test]
+    fn javascript_stays_apart_from_a_typescript_directory() {
+        let root: PathBuf = tree("detect-javascript", &["package.json", "index.js"]);
+        assert_eq!(languages(&detect(&root)), vec![Language::JavaScript]);
+        let mixed: PathBuf = tree(
+            "detect-mixed",
+            &["tsconfig.json", "src/index.ts", "scripts/build.js"],
+        );
+        assert_eq!(
+            languages(&detect(&mixed)),
+            vec![Language::JavaScript, Language::TypeScript]
+        );
+    }
+
+    #[test]
+    fn detect_grows_the_confidence_of_the_sources() {
+        let root: PathBuf = tree(
+            "detect-confidence",
+            &["a.py", "b.py", "tools/c.py", "run.sh"],
+        );
+        let detected: Vec<Detection> = detect(&root);
+        assert_eq!(languages(&detected), vec![Language::Python, Language::Bash]);
+        assert_eq!(detected[0].confidence, Confidence::Medium);
+        assert_eq!(detected[0].files, 3);
+        assert!(detected[0].is_certain());
+        assert_eq!(detected[1].confidence, Confidence::Low);
+        assert!(!detected[1].is_certain());
+    }
+
+    #[test]
+    fn detect_keeps_the_shallowest_manifest() {
+        let root: PathBuf = tree(
+            "detect-location",
+            &[
+                "crates/core/Cargo.toml",
+                "Cargo.toml",
+                "target/debug/build.rs",
+                "node_modules/x/index.js",
+            ],
+        );
+        let detected: Vec<Detection> = detect(&root);
+        assert_eq!(languages(&detected), vec![Language::Rust]);
+        assert_eq!(detected[0].location, PathBuf::new());
+        assert_eq!(detected[0].files, 0);
+    }
+}
@@ -0,0 +1,525 @@
+use crate::support::{Language, ROOT_PROJECT};
+use glob::glob;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+#[doc = "The kind of check performed by a task"]
+pub enum TaskCategory {
+    Structure,
+    License,
+    Dependencies,
+    Audit,
+    Test,
+    Format,
+    Docs,
+    Outdated,
+    Lint,
+    #[default]
+    Custom,
+}
+
+impl Display for TaskCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskCategory::Structure => write!(f, "structure"),
+            TaskCategory::License => write!(f, "license"),
+            TaskCategory::Dependencies => write!(f, "dependencies"),
+            TaskCategory::Audit => write!(f, "audit"),
+            TaskCategory::Test => write!(f, "test"),
+            TaskCategory::Format => write!(f, "format"),
+            TaskCategory::Docs => write!(f, "docs"),
+            TaskCategory::Outdated => write!(f, "outdated"),
+            TaskCategory::Lint => write!(f, "lint"),
+            TaskCategory::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[doc = "A checkup task to execute for a language"]
+pub struct Task {
+    #[doc = "Short identifier, used for the output file names"]
+    pub name: String,
+    #[doc = "Message displayed while the task is running"]
+    pub title: String,
+    #[doc = "The executable to run"]
+    pub program: String,
+    #[serde(default)]
+    #[doc = "The arguments given to the program, glob patterns are expanded"]
+    pub args: Vec<String>,
+    #[serde(default)]
+    #[doc = "Run the command line through `sh -c`"]
+    pub shell: bool,
+    #[doc = "Message displayed when the command succeeds"]
+    pub success: String,
+    #[doc = "Message displayed when the command fails"]
+    pub failure: String,
+    #[serde(default)]
+    pub category: TaskCategory,
+    #[serde(default)]
+    #[doc = "Maximum duration in seconds, the process group is terminated once reached"]
+    pub timeout: Option<u64>,
+    #[serde(default)]
+    #[doc = "Extra environment variables given to the command"]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    #[doc = "Directory where the command is executed"]
+    pub cwd: Option<PathBuf>,
+    #[serde(default)]
+    #[doc = "Names of the tasks of the same language to finish before this one"]
+    pub after: Vec<String>,
+    #[serde(default)]
+    #[doc = "Names of the tasks of the same language to pass before this one, skipped otherwise"]
+    pub needs: Vec<String>,
+    #[serde(default)]
+    #[doc = "The command accepts file arguments, `--staged` replaces its paths and patterns by the staged files"]
+    pub files: bool,
+    #[serde(default)]
+    #[doc = "Number of executions after a failure, a task passing on a retry is flaky"]
+    pub retries: u32,
+    #[serde(default)]
+    #[doc = "Seconds to wait before the first retry, doubled before each following retry"]
+    pub backoff: u64,
+    #[serde(default)]
+    #[doc = "Glob patterns of the files read by the command, relative to the task directory, the result is cached when set"]
+    pub inputs: Vec<String>,
+}
+
+impl Task {
+    ///
+    /// # New
+    ///
+    /// Create a task executed without shell, the command line is split like `sh` would do
+    ///
+    #[must_use]
+    pub fn new(
+        name: &str,
+        category: TaskCategory,
+        title: &str,
+        command: &str,
+        success: &str,
+        failure: &str,
+    ) -> Self {
+        let mut task = Self {
+            name: name.to_string(),
+            title: title.to_string(),
+            success: success.to_string(),
+            failure: failure.to_string(),
+            category,
+            ..Self::default()
+        };
+        task.set_command(command);
+        task
+    }
+
+    ///
+    /// # Shell
+    ///
+    /// Create a task executed by `sh -c`, for the commands using pipes, redirections or `&&`
+    ///
+    #[must_use]
+    pub fn shell(
+        name: &str,
+        category: TaskCategory,
+        title: &str,
+        command: &str,
+        success: &str,
+        failure: &str,
+    ) -> Self {
+        let mut task = Self::new(name, category, title, "", success, failure);
+        task.set_shell_command(command);
+        task
+    }
+
+    #[doc = "Replace the program and the arguments by the split command line"]
+    pub fn set_command(&mut self, command: &str) {
+        let mut argv: Vec<String> = shell_words::split(command)
+            .unwrap_or_else(|_| command.split_whitespace().map(String::from).collect());
+        self.program = if argv.is_empty() {
+            String::new()
+        } else {
+            argv.remove(0)
+        };
+        self.args = argv;
+        self.shell = false;
+    }
+
+    #[doc = "Replace the command by a command line executed by `sh -c`"]
+    pub fn set_shell_command(&mut self, command: &str) {
+        self.program = String::from("sh");
+        self.args = vec![String::from("-c"), command.to_string()];
+        self.shell = true;
+    }
+
+    #[must_use]
+    #[doc = "Stop the task after the given number of seconds"]
+    pub const fn with_timeout(mut self, seconds: u64) -> Self {
+        self.timeout = Some(seconds);
+        self
+    }
+
+    #[must_use]
+    #[doc = "Add an environment variable to the command"]
+    pub fn with_env(mut self, key: &str, value: &str) -> Self {
+        self.env.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    #[doc = "Execute the command inside the given directory"]
+    pub fn with_cwd(mut self, cwd: &str) -> Self {
+        self.cwd = Some(PathBuf::from(cwd));
+        self
+    }
+
+    #[must_use]
+    #[doc = "Declare that the command accepts file arguments"]
+    pub const fn with_files(mut self) -> Self {
+        self.files = true;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Execute the task again after a failure, waiting `backoff` seconds doubled at each retry"]
+    pub const fn with_retries(mut self, retries: u32, backoff: u64) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    #[must_use]
+    #[doc = "Cache the result until the files matched by the patterns change"]
+    pub fn with_inputs(mut self, patterns: &[&str]) -> Self {
+        self.inputs = patterns.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    ///
+    /// # Targeting
+    ///
+    /// Replace the `.` and glob pattern arguments by the given files
+    ///
+    #[must_use]
+    pub fn targeting(mut self, files: &[PathBuf]) -> Self {
+        self.args
+            .retain(|arg| arg.ne(".") && arg.ne("./") && !arg.contains(['*', '?', '[']));
+        self.args
+            .extend(files.iter().map(|file| file.to_string_lossy().to_string()));
+        self
+    }
+
+    #[must_use]
+    #[doc = "Execute the task inside the project root, the task directory is relative to it"]
+    pub fn within(mut self, root: &Path) -> Self {
+        self.cwd = Some(
+            self.cwd
+                .as_ref()
+                .map_or_else(|| root.to_path_buf(), |cwd| root.join(cwd)),
+        );
+        self
+    }
+
+    #[must_use]
+    #[doc = "Start the task once the given tasks are finished"]
+    pub fn with_after(mut self, names: &[&str]) -> Self {
+        self.after = names.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    #[must_use]
+    #[doc = "Start the task once the given tasks have passed, skip it otherwise"]
+    pub fn with_needs(mut self, names: &[&str]) -> Self {
+        self.needs = names.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    #[must_use]
+    #[doc = "The names of the tasks to finish before this one"]
+    pub fn prerequisites(&self) -> Vec<&String> {
+        self.after.iter().chain(self.needs.iter()).collect()
+    }
+
+    #[must_use]
+    #[doc = "The name of the files storing the task output"]
+    pub fn output_file(&self) -> String {
+        format!("{}.txt", self.name)
+    }
+
+    #[must_use]
+    #[doc = "The name of the files keeping the output of a failed attempt"]
+    pub fn attempt_file(&self, attempt: u32) -> String {
+        format!("{}.{attempt}.txt", self.name)
+    }
+
+    #[must_use]
+    #[doc = "The program executed, the first word of a shell command line"]
+    pub fn executable(&self) -> String {
+        if !self.shell {
+            return self.program.to_string();
+        }
+        shell_words::split(&self.command_line())
+            .ok()
+            .and_then(|argv| argv.into_iter().next())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    #[doc = "The command line as displayed to the user"]
+    pub fn command_line(&self) -> String {
+        if self.shell {
+            return self.args.last().cloned().unwrap_or_default();
+        }
+        let mut argv: Vec<&str> = vec![self.program.as_str()];
+        argv.extend(self.args.iter().map(String::as_str));
+        shell_words::join(argv)
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// The arguments with the glob patterns expanded from the task directory.
+    /// A pattern without match is given as is, like `sh` does.
+    ///
+    #[must_use]
+    pub fn arguments(&self) -> Vec<String> {
+        if self.shell {
+            return self.args.clone();
+        }
+        let base: PathBuf = self.cwd.clone().unwrap_or_default();
+        let mut arguments: Vec<String> = Vec::new();
+        for arg in &self.args {
+            if !arg.contains(['*', '?', '[']) {
+                arguments.push(arg.to_string());
+                continue;
+            }
+            let pattern: PathBuf = base.join(arg);
+            let mut matches: Vec<String> = glob(pattern.to_string_lossy().as_ref())
+                .map(|paths| {
+                    paths
+                        .filter_map(Result::ok)
+                        .map(|path| {
+                            path.strip_prefix(&base)
+                                .unwrap_or(&path)
+                                .to_string_lossy()
+                                .to_string()
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            if matches.is_empty() {
+                arguments.push(arg.to_string());
+            } else {
+                arguments.append(&mut matches);
+            }
+        }
+        arguments
+    }
+
+    #[must_use]
+    #[doc = "Build the process to execute in its own process group, without shell unless asked"]
+    pub fn to_command(&self) -> Command {
+        let mut command: Command = Command::new(&self.program);
+        command
+            .args(self.arguments())
+            .envs(&self.env)
+            .stdin(Stdio::null());
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+        command
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[doc = "The state of an executed task"]
+pub enum TaskStatus {
+    Pass,
+    Fail,
+    #[doc = "Not executed because a needed task has not passed"]
+    Skipped,
+    #[doc = "Not executed because no file of its language has changed"]
+    Unaffected,
+    #[doc = "Stopped because it has run longer than its timeout"]
+    Timeout,
+    #[doc = "Stopped or not started because the run has been interrupted"]
+    Cancelled,
+    #[doc = "Passed after a failure, on a retry"]
+    Flaky,
+    #[doc = "Not executed because its program is not installed"]
+    Missing,
+}
+
+impl TaskStatus {
+    #[must_use]
+    #[doc = "Check if the task has passed, on the first execution or on a retry"]
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Pass | Self::Flaky)
+    }
+
+    #[must_use]
+    #[doc = "Check if the task has failed, has been stopped or could not be executed"]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail | Self::Timeout | Self::Missing)
+    }
+
+    #[must_use]
+    #[doc = "Check if the task has not been executed without failing, a missing tool is a failure"]
+    pub fn is_skipped(&self) -> bool {
+        !self.is_executed() && !self.is_failure()
+    }
+
+    #[must_use]
+    #[doc = "Check if the command has been executed"]
+    pub fn is_executed(&self) -> bool {
+        matches!(self, Self::Pass | Self::Fail | Self::Timeout | Self::Flaky)
+    }
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskStatus::Pass => write!(f, "PASS"),
+            TaskStatus::Fail => write!(f, "FAIL"),
+            TaskStatus::Skipped => write!(f, "SKIPPED"),
+            TaskStatus::Unaffected => write!(f, "UNAFFECTED"),
+            TaskStatus::Timeout => write!(f, "TIMEOUT"),
+            TaskStatus::Cancelled => write!(f, "CANCELLED"),
+            TaskStatus::Flaky => write!(f, "FLAKY"),
+            TaskStatus::Missing => write!(f, "MISSING TOOL"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[doc = "The result of a task execution"]
+pub struct TaskResult {
+    #[doc = "The name of the project, `.` for the repository root"]
+    pub project: String,
+    pub language: Language,
+    pub task: Task,
+    #[doc = "The command line executed"]
+    pub command: String,
+    pub status: TaskStatus,
+    #[doc = "The exit code of the command, none when not executed or killed by a signal"]
+    pub exit_code: Option<i32>,
+    #[serde(serialize_with = "seconds")]
+    #[doc = "Time spent by the command, serialized in seconds"]
+    pub duration: Duration,
+    #[doc = "File storing the standard output"]
+    pub stdout: PathBuf,
+    #[doc = "File storing the error output"]
+    pub stderr: PathBuf,
+    #[doc = "The outputs and the status are replayed from the cache"]
+    pub cached: bool,
+    #[doc = "Number of executions, 0 when not executed or replayed from the cache"]
+    pub attempts: u32,
+}
+
+impl TaskResult {
+    #[must_use]
+    #[doc = "The task name prefixed by its language, and by its project outside the repository root"]
+    pub fn id(&self) -> String {
+        if self.project.eq(ROOT_PROJECT) {
+            format!("{}/{}", self.language, self.task.name)
+        } else {
+            format!("{}/{}/{}", self.project, self.language, self.task.name)
+        }
+    }
+}
+
+///
+/// # Seconds
+///
+/// Serialize a duration as seconds
+///
+/// # Errors
+///
+/// On serializer failure
+///
+pub fn seconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch;
+    use std::fs::{remove_dir_all, write};
+
+    fn task(command: &str) -> Task {
+        Task::new("lint", TaskCategory::Lint, "", command, "", "")
+    }
+
+    #[test]
+    fn set_command_splits_the_quoted_arguments() {
+        let task: Task = task(r#"cargo clippy -- -D "clippy::all" --cfg 'feature="a b"'"#);
+        assert!(!task.shell);
+        assert_eq!(task.program, "cargo");
+        assert_eq!(
+            task.args,
+            vec![
+                "clippy",
+                "--",
+                "-D",
+                "clippy::all",
+                "--cfg",
+                "feature=\"a b\""
+            ]
+        );
+        assert_eq!(
+            task.command_line(),
+            r#"cargo clippy -- -D clippy::all --cfg 'feature="a b"'"#
+        );
+    }
+
+    #[test]
+    fn arguments_expand_the_globs_from_the_task_directory() {
+        let directory: PathBuf = scratch("arguments");
+        for file in ["b.sh", "a.sh", "notes.txt"] {
+            write(directory.join(file), "").expect("the file is written");
+        }
+        let cwd: String = directory.to_string_lossy().to_string();
+        let expanded: Task = task("shellcheck -x *.sh 'a b.sh'").with_cwd(&cwd);
+        assert_eq!(expanded.arguments(), vec!["-x", "a.sh", "b.sh", "a b.sh"]);
+        let unmatched: Task = task("flake8 *.py").with_cwd(&cwd);
+        assert_eq!(unmatched.arguments(), vec!["*.py"]);
+        remove_dir_all(&directory).expect("the directory is removed");
+    }
+
+    #[test]
+    fn shell_commands_are_given_as_is_to_sh() {
+        let mut shell: Task = task("");
+        shell.set_shell_command("ls *.sh | wc -l");
+        assert!(shell.shell);
+        assert_eq!(shell.program, "sh");
+        assert_eq!(shell.arguments(), vec!["-c", "ls *.sh | wc -l"]);
+        assert_eq!(shell.command_line(), "ls *.sh | wc -l");
+        assert_eq!(shell.to_command().get_program(), "sh");
+    }
+
+    #[test]
+    fn targeting_replaces_the_directory_and_the_globs() {
+        let targeted: Task = task("black --check . src/*.py").targeting(&[
+            PathBuf::from("src/app.py"),
+            PathBuf::from("tests/test app.py"),
+        ]);
+        assert_eq!(
+            targeted.args,
+            vec!["--check", "src/app.py", "tests/test app.py"]
+        );
+    }
+}
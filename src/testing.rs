@@ -0,0 +1,58 @@
+use std::env::{current_dir, set_current_dir, temp_dir};
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[doc = "Serialize the tests changing the current directory, it is shared by the threads"]
+static DIRECTORY: Mutex<()> = Mutex::new(());
+
+#[doc = "Put back the current directory when the test ends, even on panic"]
+struct Within {
+    previous: PathBuf,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for Within {
+    fn drop(&mut self) {
+        let _ = set_current_dir(&self.previous);
+    }
+}
+
+#[doc = "An empty directory for the test, removed first when a previous run left it"]
+pub fn scratch(name: &str) -> PathBuf {
+    let directory: PathBuf = temp_dir().join(format!("tux-{name}-{}", std::process::id()));
+    let _ = remove_dir_all(&directory);
+    create_dir_all(&directory).expect("the scratch directory is created");
+    directory
+}
+
+#[doc = "Run git in the directory, panics on failure"]
+pub fn git(directory: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=tux", "-c", "user.email=tux@localhost"])
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .expect("git is installed")
+        .status;
+    assert!(status.success(), "git {} failed", args.join(" "));
+}
+
+#[doc = "An empty git repository for the test"]
+pub fn repository(name: &str) -> PathBuf {
+    let directory: PathBuf = scratch(name);
+    git(&directory, &["init", "-q"]);
+    directory
+}
+
+#[doc = "Run the test from the directory, one test at a time"]
+pub fn within<T>(directory: &Path, test: impl FnOnce() -> T) -> T {
+    let lock: MutexGuard<'static, ()> = DIRECTORY.lock().unwrap_or_else(PoisonError::into_inner);
+    let _within: Within = Within {
+        previous: current_dir().expect("the current directory exists"),
+        _lock: lock,
+    };
+    set_current_dir(directory).expect("the test directory exists");
+    test()
+}
@@ -0,0 +1,268 @@
+use crate::interrupt::cancelled;
+use crate::support::{language_of, Language, Project};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::{Match, WalkBuilder};
+use notify::event::CreateKind;
+use notify::{recommended_watcher, Event, EventKind, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+#[doc = "The quiet time closing a burst of changes"]
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[doc = "The configuration file, every project is checked again when it changes"]
+pub const CONFIG_FILE: &str = "tux.toml";
+
+#[doc = "The paths never watched, tux writes its output in zuu"]
+pub const ALWAYS_IGNORED: [&str; 2] = [".git/", "zuu/"];
+
+#[doc = "The matcher of the `.gitignore` and `.git/info/exclude` files of the root"]
+fn root_ignored(root: &Path) -> Gitignore {
+    let mut builder: GitignoreBuilder = GitignoreBuilder::new(root);
+    for line in ALWAYS_IGNORED {
+        let _ = builder.add_line(None, line);
+    }
+    let _ = builder.add(root.join(".gitignore"));
+    let _ = builder.add(root.join(".git/info/exclude"));
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+#[doc = "The directories under the given one not ignored, itself first"]
+fn directories(directory: &Path, root: &Gitignore) -> Vec<PathBuf> {
+    let always: Gitignore = root.clone();
+    WalkBuilder::new(directory)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(move |entry| {
+            let is_dir: bool = entry.file_type().is_some_and(|kind| kind.is_dir());
+            !entry.path().starts_with(always.path())
+                || !always
+                    .matched_path_or_any_parents(entry.path(), is_dir)
+                    .is_ignore()
+        })
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|kind| kind.is_dir()))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+///
+/// # Ignored
+///
+/// The matchers of the `.gitignore` files of the tree, the root one first
+/// with `.git/info/exclude`, the ones of the ignored directories are not read
+///
+#[must_use]
+pub fn ignored(root: &Path) -> Vec<Gitignore> {
+    let mut matchers: Vec<Gitignore> = vec![root_ignored(root)];
+    for directory in directories(root, &matchers[0]).into_iter().skip(1) {
+        let (matcher, _) = Gitignore::new(directory.join(".gitignore"));
+        if !matcher.is_empty() {
+            matchers.push(matcher);
+        }
+    }
+    matchers
+}
+
+#[doc = "Check if an absolute path is ignored, the deepest `.gitignore` matching it decides"]
+fn is_ignored(matchers: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    for matcher in matchers.iter().rev() {
+        if !path.starts_with(matcher.path()) {
+            continue;
+        }
+        match matcher.matched_path_or_any_parents(path, is_dir) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => {}
+        }
+    }
+    false
+}
+
+#[doc = "Watch the directories not ignored, one by one, a recursive watch would enter the ignored trees"]
+fn follow(
+    watcher: &mut impl Watcher,
+    directory: &Path,
+    matchers: &[Gitignore],
+) -> notify::Result<()> {
+    for found in directories(directory, &matchers[0]) {
+        if !is_ignored(matchers, &found, true) {
+            watcher.watch(&found, RecursiveMode::NonRecursive)?;
+        }
+    }
+    Ok(())
+}
+
+///
+/// # Affected
+///
+/// The projects and languages to check again for the changed paths, relative to the root.
+/// `None` when the configuration has changed and everything must be checked.
+///
+#[must_use]
+pub fn affected(projects: &[Project], changed: &[PathBuf]) -> Option<Vec<(String, Language)>> {
+    let mut keys: Vec<(String, Language)> = Vec::new();
+    for path in changed {
+        if path.eq(Path::new(CONFIG_FILE)) {
+            return None;
+        }
+        let Some(language) = language_of(path) else {
+            continue;
+        };
+        for project in projects {
+            let inside: bool = project.is_root() || path.starts_with(&project.root);
+            let key: (String, Language) = (project.name.to_string(), language);
+            if inside && project.languages.contains(&language) && !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    Some(keys)
+}
+
+#[doc = "Wait for the end of a burst of changes, the paths are relative to the root. None once cancelled."]
+fn burst(
+    events: &Receiver<notify::Result<Event>>,
+    root: &Path,
+    watcher: &mut impl Watcher,
+    matchers: &[Gitignore],
+) -> Option<Vec<PathBuf>> {
+    let mut changed: Vec<PathBuf> = Vec::new();
+    loop {
+        if cancelled() {
+            return None;
+        }
+        let event: Event = match events.recv_timeout(DEBOUNCE) {
+            Ok(Ok(event)) => event,
+            Ok(Err(_)) => continue,
+            Err(RecvTimeoutError::Timeout) if changed.is_empty() => continue,
+            Err(RecvTimeoutError::Timeout) => return Some(changed),
+            Err(RecvTimeoutError::Disconnected) => return None,
+        };
+        if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+            continue;
+        }
+        for path in event.paths {
+            let relative: PathBuf = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            if relative.has_root()
+                || is_ignored(matchers, &path, path.is_dir())
+                || changed.contains(&relative)
+            {
+                continue;
+            }
+            if matches!(event.kind, EventKind::Create(CreateKind::Folder))
+                || (matches!(event.kind, EventKind::Create(_)) && path.is_dir())
+            {
+                let _ = follow(watcher, &path, matchers);
+            }
+            changed.push(relative);
+        }
+    }
+}
+
+///
+/// # Watch
+///
+/// Call `run` with the changed paths after each burst of changes of the tree
+/// until the run is cancelled. The directories ignored by git are not watched,
+/// the directories created during the watch are followed.
+///
+/// # Errors
+///
+/// When the tree can not be watched
+///
+pub fn watch(root: &Path, mut run: impl FnMut(&[PathBuf])) -> notify::Result<()> {
+    let root: PathBuf = root.canonicalize()?;
+    let matchers: Vec<Gitignore> = ignored(&root);
+    let (sender, events) = channel::<notify::Result<Event>>();
+    let mut watcher = recommended_watcher(sender)?;
+    follow(&mut watcher, &root, &matchers)?;
+    while let Some(changed) = burst(&events, &root, &mut watcher, &matchers) {
+        run(&changed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::ROOT_PROJECT;
+    use crate::testing::scratch;
+    use std::fs::{create_dir_all, write};
+
+    #[doc = "A tree with the root, a nested and an ignored `.gitignore`"]
+    fn tree(name: &str) -> PathBuf {
+        let root: PathBuf = scratch(name);
+        for directory in [".git/info", "src", "out", "zuu"] {
+            create_dir_all(root.join(directory)).expect("the directory is created");
+        }
+        write(root.join(".gitignore"), "*.log\n/out/\n").expect("the gitignore is written");
+        write(root.join(".git/info/exclude"), "secret.txt\n").expect("the exclude is written");
+        write(root.join("src/.gitignore"), "generated.rs\n!keep.log\n")
+            .expect("the gitignore is written");
+        write(root.join("out/.gitignore"), "!*\n").expect("the gitignore is written");
+        root
+    }
+
+    #[test]
+    fn is_ignored_follows_the_deepest_gitignore() {
+        let root: PathBuf = tree("watch-deepest");
+        let matchers: Vec<Gitignore> = ignored(&root);
+        assert_eq!(matchers.len(), 2);
+        assert!(is_ignored(&matchers, &root.join("app.log"), false));
+        assert!(is_ignored(&matchers, &root.join("secret.txt"), false));
+        assert!(is_ignored(&matchers, &root.join("src/generated.rs"), false));
+        assert!(!is_ignored(&matchers, &root.join("src/main.rs"), false));
+        assert!(!is_ignored(&matchers, &root.join("src/keep.log"), false));
+        assert!(!is_ignored(&matchers, &root.join("generated.rs"), false));
+    }
+
+    #[test]
+    fn is_ignored_covers_the_ignored_directories() {
+        let root: PathBuf = tree("watch-directories");
+        let matchers: Vec<Gitignore> = ignored(&root);
+        assert!(is_ignored(&matchers, &root.join("out"), true));
+        assert!(is_ignored(&matchers, &root.join("out/main.rs"), false));
+        assert!(is_ignored(
+            &matchers,
+            &root.join("zuu/rust/test.txt"),
+            false
+        ));
+        assert!(is_ignored(&matchers, &root.join(".git/index"), false));
+        assert!(!is_ignored(&matchers, &root.join("src"), true));
+        assert!(!directories(&root, &matchers[0])
+            .iter()
+            .any(|directory| directory.starts_with(root.join("out"))));
+    }
+
+    #[test]
+    fn affected_keeps_the_projects_of_the_changed_languages() {
+        let projects: Vec<Project> = vec![
+            Project {
+                name: ROOT_PROJECT.to_string(),
+                root: PathBuf::from(ROOT_PROJECT),
+                languages: vec![Language::Rust],
+            },
+            Project {
+                name: String::from("web"),
+                root: PathBuf::from("web"),
+                languages: vec![Language::TypeScript],
+            },
+        ];
+        let changed: Vec<PathBuf> = vec![
+            PathBuf::from("web/src/app.ts"),
+            PathBuf::from("src/lib.rs"),
+            PathBuf::from("web/src/main.ts"),
+        ];
+        assert_eq!(
+            affected(&projects, &changed),
+            Some(vec![
+                (String::from("web"), Language::TypeScript),
+                (ROOT_PROJECT.to_string(), Language::Rust),
+            ])
+        );
+        assert_eq!(affected(&projects, &[PathBuf::from(CONFIG_FILE)]), None);
+    }
+}